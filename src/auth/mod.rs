@@ -0,0 +1,278 @@
+//! Obtaining OAuth 2.0 access tokens for the [`Client`](crate::Client).
+use std::{
+    fmt::{self, Display, Formatter},
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{Mutex, MutexGuard};
+
+use crate::{error::AuthError, Error, Result};
+
+mod refresh_token;
+mod service_account;
+
+pub use refresh_token::RefreshToken;
+pub use service_account::{ServiceAccount, ServiceAccountKey};
+
+/// Tokens are refreshed this long before they expire, so they never lapse mid-request.
+const EXPIRY_MARGIN: Duration = Duration::from_mins(1);
+
+/// The default Google OAuth 2.0 token endpoint.
+pub const TOKEN_URI: &str = "https://oauth2.googleapis.com/token";
+
+/// OAuth 2.0 scopes used by the Classroom API.
+pub mod scopes {
+    /// View and manage Classroom classes.
+    pub const COURSES: &str = "https://www.googleapis.com/auth/classroom.courses";
+    /// View Classroom classes.
+    pub const COURSES_READONLY: &str = "https://www.googleapis.com/auth/classroom.courses.readonly";
+    /// Manage class rosters.
+    pub const ROSTERS: &str = "https://www.googleapis.com/auth/classroom.rosters";
+    /// View class rosters.
+    pub const ROSTERS_READONLY: &str = "https://www.googleapis.com/auth/classroom.rosters.readonly";
+    /// View the email addresses of people in classes.
+    pub const PROFILE_EMAILS: &str = "https://www.googleapis.com/auth/classroom.profile.emails";
+    /// View the profile photos of people in classes.
+    pub const PROFILE_PHOTOS: &str = "https://www.googleapis.com/auth/classroom.profile.photos";
+    /// Manage announcements.
+    pub const ANNOUNCEMENTS: &str = "https://www.googleapis.com/auth/classroom.announcements";
+    /// Manage course work and grades for students.
+    pub const COURSEWORK_STUDENTS: &str =
+        "https://www.googleapis.com/auth/classroom.coursework.students";
+    /// Manage your own course work and grades.
+    pub const COURSEWORK_ME: &str = "https://www.googleapis.com/auth/classroom.coursework.me";
+    /// Manage course work materials.
+    pub const COURSEWORKMATERIALS: &str =
+        "https://www.googleapis.com/auth/classroom.courseworkmaterials";
+    /// Manage topics.
+    pub const TOPICS: &str = "https://www.googleapis.com/auth/classroom.topics";
+    /// Manage guardians of students.
+    pub const GUARDIANLINKS_STUDENTS: &str =
+        "https://www.googleapis.com/auth/classroom.guardianlinks.students";
+    /// Receive notifications about Classroom data.
+    pub const PUSH_NOTIFICATIONS: &str =
+        "https://www.googleapis.com/auth/classroom.push-notifications";
+}
+
+/// Errors while loading credentials.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The key file could not be read.
+    Io(std::io::Error),
+    /// The key file is not valid JSON, or is missing fields.
+    Json(serde_json::Error),
+    /// The private key could not be parsed.
+    Key(jsonwebtoken::errors::Error),
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(source) => write!(f, "could not read key file: {source}"),
+            Self::Json(source) => write!(f, "invalid key file: {source}"),
+            Self::Key(source) => write!(f, "invalid private key: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(source) => Some(source),
+            Self::Json(source) => Some(source),
+            Self::Key(source) => Some(source),
+        }
+    }
+}
+
+/// Supplies the access token sent with every request made by a [`Client`](crate::Client).
+///
+/// Implementations are expected to cache tokens, as this is called once per request.
+#[async_trait]
+pub trait TokenProvider: Send + Sync {
+    /// Get a valid access token, refreshing it first if necessary.
+    ///
+    /// `http` is the client's own HTTP client, for use when talking to a token endpoint.
+    ///
+    /// # Errors
+    /// Errors if no token could be obtained.
+    async fn access_token(&self, http: &reqwest::Client) -> Result<String>;
+
+    /// The user whose access tokens are provided through domain-wide delegation, if any. Used to give each user their
+    /// own [`RateLimit`](crate::client::RateLimit) budgets.
+    fn impersonated(&self) -> Option<&str> {
+        None
+    }
+}
+
+#[async_trait]
+impl<T: TokenProvider + ?Sized> TokenProvider for Arc<T> {
+    async fn access_token(&self, http: &reqwest::Client) -> Result<String> {
+        (**self).access_token(http).await
+    }
+
+    fn impersonated(&self) -> Option<&str> {
+        (**self).impersonated()
+    }
+}
+
+/// A pre-obtained access token, sent as-is. Useful for tests, or when tokens are managed elsewhere.
+#[derive(Clone, PartialEq, Eq)]
+pub struct StaticToken(pub String);
+
+impl StaticToken {
+    /// Wrap `access_token`.
+    pub fn new(access_token: impl Into<String>) -> Self {
+        Self(access_token.into())
+    }
+}
+
+#[async_trait]
+impl TokenProvider for StaticToken {
+    async fn access_token(&self, _http: &reqwest::Client) -> Result<String> {
+        Ok(self.0.clone())
+    }
+}
+
+impl std::fmt::Debug for StaticToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("StaticToken(..)")
+    }
+}
+
+/// Persists the tokens held by a [`RefreshToken`], so they survive restarts and refresh token rotation is not lost.
+#[async_trait]
+pub trait TokenStore: Send + Sync {
+    /// Load the most recently saved token, if any.
+    ///
+    /// # Errors
+    /// Errors if the backing storage could not be read.
+    async fn load(&self) -> Result<Option<StoredToken>, BoxError>;
+
+    /// Save a freshly obtained token.
+    ///
+    /// If this fails, the error is returned from [`TokenProvider::access_token`], but the new token is still cached
+    /// and used by later calls.
+    ///
+    /// # Errors
+    /// Errors if the backing storage could not be written.
+    async fn save(&self, token: &StoredToken) -> Result<(), BoxError>;
+}
+
+/// Error type returned by [`TokenStore`] implementations.
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Tokens saved by a [`TokenStore`].
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct StoredToken {
+    /// The most recent access token.
+    pub access_token: String,
+    /// When `access_token` expires.
+    pub expires_at: SystemTime,
+    /// The refresh token used to obtain new access tokens.
+    pub refresh_token: String,
+}
+
+impl std::fmt::Debug for StoredToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StoredToken")
+            .field("expires_at", &self.expires_at)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An access token and when it expires.
+#[derive(Debug, Clone)]
+struct CachedToken {
+    access_token: String,
+    expires_at: SystemTime,
+}
+
+impl CachedToken {
+    fn new(response: &TokenResponse) -> Self {
+        Self {
+            access_token: response.access_token.clone(),
+            expires_at: SystemTime::now() + Duration::from_secs(response.expires_in),
+        }
+    }
+
+    fn is_fresh(&self) -> bool {
+        SystemTime::now() + EXPIRY_MARGIN < self.expires_at
+    }
+}
+
+/// The cached token of a provider.
+///
+/// The token lock is only held briefly. Refreshing goes through a separate lock, so concurrent callers wait for a
+/// single refresh instead of each requesting a token, while callers with a fresh token are never blocked by it. The
+/// refresh lock also guards `R`, any state that only the refreshing caller needs.
+#[derive(Debug, Default)]
+struct TokenCache<R = ()> {
+    token: Mutex<Option<CachedToken>>,
+    refresh: Mutex<R>,
+}
+
+impl<R> TokenCache<R> {
+    fn new(refresh: R) -> Self {
+        Self {
+            token: Mutex::new(None),
+            refresh: Mutex::new(refresh),
+        }
+    }
+
+    /// The cached access token, unless it is missing or about to expire.
+    async fn fresh(&self) -> Option<String> {
+        self.token
+            .lock()
+            .await
+            .as_ref()
+            .filter(|token| token.is_fresh())
+            .map(|token| token.access_token.clone())
+    }
+
+    /// Replace the cached token.
+    async fn insert(&self, token: CachedToken) {
+        *self.token.lock().await = Some(token);
+    }
+
+    /// Wait for any refresh in progress and hold off others until the guard is dropped. Check [`TokenCache::fresh`]
+    /// again afterwards, as the refresh waited for may have left a fresh token.
+    async fn refreshing(&self) -> MutexGuard<'_, R> {
+        self.refresh.lock().await
+    }
+
+    /// Forget the cached token.
+    fn clear(&mut self) {
+        *self.token.get_mut() = None;
+    }
+}
+
+/// Successful response from a token endpoint.
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: u64,
+    refresh_token: Option<String>,
+}
+
+/// POST `form` to the token endpoint at `token_uri`.
+async fn request_token(
+    http: &reqwest::Client,
+    token_uri: &str,
+    form: &[(&str, &str)],
+) -> Result<TokenResponse> {
+    let response = http.post(token_uri).form(form).send().await?;
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        let error = serde_json::from_str(&body).unwrap_or_else(|_| AuthError {
+            error: status.to_string(),
+            error_description: Some(body),
+        });
+        return Err(Error::Auth(error));
+    }
+    serde_json::from_str(&body).map_err(|source| Error::Decode { source, body })
+}
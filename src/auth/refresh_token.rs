@@ -0,0 +1,274 @@
+use async_trait::async_trait;
+
+use super::{
+    request_token, CachedToken, StoredToken, TokenCache, TokenProvider, TokenStore, TOKEN_URI,
+};
+use crate::{Error, Result};
+
+/// User credentials from an installed or web application, exchanged for access tokens with a refresh token.
+///
+/// Access tokens are cached until shortly before they expire. If a [`TokenStore`] is attached, it is consulted before
+/// the first refresh and updated after every refresh.
+#[allow(clippy::module_name_repetitions)]
+pub struct RefreshToken {
+    client_id: String,
+    client_secret: String,
+    token_uri: String,
+    store: Option<Box<dyn TokenStore>>,
+    cache: TokenCache<Refresh>,
+}
+
+/// State used while refreshing, guarded by the refresh lock of the [`TokenCache`].
+struct Refresh {
+    refresh_token: String,
+    /// Whether the [`TokenStore`] has been consulted.
+    loaded: bool,
+}
+
+impl RefreshToken {
+    /// Create credentials for the OAuth 2.0 client `client_id`, holding `refresh_token`.
+    pub fn new(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        refresh_token: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            token_uri: TOKEN_URI.to_owned(),
+            store: None,
+            cache: TokenCache::new(Refresh {
+                refresh_token: refresh_token.into(),
+                loaded: false,
+            }),
+        }
+    }
+
+    /// Refresh tokens at `token_uri` instead of [`TOKEN_URI`].
+    #[must_use]
+    pub fn token_uri(mut self, token_uri: impl Into<String>) -> Self {
+        self.token_uri = token_uri.into();
+        self
+    }
+
+    /// Load and save tokens through `store`. A refresh token found in the store takes precedence over the one given
+    /// to [`RefreshToken::new`], as it may have been rotated since.
+    #[must_use]
+    pub fn store(mut self, store: impl TokenStore + 'static) -> Self {
+        self.store = Some(Box::new(store));
+        self
+    }
+}
+
+#[async_trait]
+impl TokenProvider for RefreshToken {
+    async fn access_token(&self, http: &reqwest::Client) -> Result<String> {
+        if let Some(access_token) = self.cache.fresh().await {
+            return Ok(access_token);
+        }
+        let mut refresh = self.cache.refreshing().await;
+        if let (Some(store), false) = (&self.store, refresh.loaded) {
+            if let Some(stored) = store.load().await.map_err(Error::TokenStore)? {
+                refresh.refresh_token = stored.refresh_token;
+                let token = CachedToken {
+                    access_token: stored.access_token,
+                    expires_at: stored.expires_at,
+                };
+                self.cache.insert(token).await;
+            }
+            refresh.loaded = true;
+        }
+        if let Some(access_token) = self.cache.fresh().await {
+            return Ok(access_token);
+        }
+        let response = request_token(
+            http,
+            &self.token_uri,
+            &[
+                ("grant_type", "refresh_token"),
+                ("client_id", &self.client_id),
+                ("client_secret", &self.client_secret),
+                ("refresh_token", &refresh.refresh_token),
+            ],
+        )
+        .await?;
+        if let Some(refresh_token) = response.refresh_token.clone() {
+            refresh.refresh_token = refresh_token;
+        }
+        // Cache before saving, so a store failure does not throw away a token that is already valid.
+        let token = CachedToken::new(&response);
+        self.cache.insert(token.clone()).await;
+        let stored = StoredToken {
+            access_token: token.access_token.clone(),
+            expires_at: token.expires_at,
+            refresh_token: refresh.refresh_token.clone(),
+        };
+        drop(refresh);
+        if let Some(store) = &self.store {
+            store.save(&stored).await.map_err(Error::TokenStore)?;
+        }
+        Ok(token.access_token)
+    }
+}
+
+impl std::fmt::Debug for RefreshToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RefreshToken")
+            .field("client_id", &self.client_id)
+            .field("token_uri", &self.token_uri)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicU32, Ordering},
+            Arc, Mutex,
+        },
+        time::{Duration, SystemTime},
+    };
+
+    use super::*;
+    use crate::{auth::BoxError, test_server::TestServer};
+
+    /// A [`TokenStore`] in memory, which fails to save if `fail` is set.
+    #[derive(Clone, Default)]
+    struct MemoryStore {
+        token: Arc<Mutex<Option<StoredToken>>>,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl TokenStore for MemoryStore {
+        async fn load(&self) -> Result<Option<StoredToken>, BoxError> {
+            Ok(self.token.lock().unwrap().clone())
+        }
+
+        async fn save(&self, token: &StoredToken) -> Result<(), BoxError> {
+            if self.fail {
+                return Err("disk full".into());
+            }
+            *self.token.lock().unwrap() = Some(token.clone());
+            Ok(())
+        }
+    }
+
+    /// Serve tokens expiring after `expires_in`, each with a rotated refresh token. The `n`th access token issued is
+    /// `access-n` and comes with the refresh token `refresh-n`.
+    fn serve_tokens(expires_in: u64) -> (TestServer, RefreshToken) {
+        let count = AtomicU32::new(0);
+        let server = TestServer::start(move |_| {
+            let issued = count.fetch_add(1, Ordering::Relaxed) + 1;
+            let body = format!(
+                r#"{{"access_token": "access-{issued}", "expires_in": {expires_in}, "refresh_token": "refresh-{issued}"}}"#
+            );
+            (200, body)
+        });
+        let tokens = RefreshToken::new("client", "secret", "refresh-0")
+            .token_uri(format!("{}/token", server.url()));
+        (server, tokens)
+    }
+
+    #[tokio::test]
+    async fn exchanges_refresh_token() {
+        let (server, tokens) = serve_tokens(3599);
+        let access_token = tokens.access_token(&reqwest::Client::new()).await.unwrap();
+        assert_eq!(access_token, "access-1");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].target, "/token");
+        let form = requests[0].form();
+        assert_eq!(form["grant_type"], "refresh_token");
+        assert_eq!(form["client_id"], "client");
+        assert_eq!(form["client_secret"], "secret");
+        assert_eq!(form["refresh_token"], "refresh-0");
+    }
+
+    #[tokio::test]
+    async fn caches_token() {
+        let (server, tokens) = serve_tokens(3599);
+        let http = reqwest::Client::new();
+        tokens.access_token(&http).await.unwrap();
+        let access_token = tokens.access_token(&http).await.unwrap();
+        assert_eq!(access_token, "access-1");
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn refreshes_token_about_to_expire_with_rotated_refresh_token() {
+        let (server, tokens) = serve_tokens(30);
+        let http = reqwest::Client::new();
+        tokens.access_token(&http).await.unwrap();
+        let access_token = tokens.access_token(&http).await.unwrap();
+        assert_eq!(access_token, "access-2");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].form()["refresh_token"], "refresh-1");
+    }
+
+    #[tokio::test]
+    async fn saves_new_token_to_store() {
+        let (_server, tokens) = serve_tokens(3599);
+        let store = MemoryStore::default();
+        let tokens = tokens.store(store.clone());
+        let before = SystemTime::now();
+        tokens.access_token(&reqwest::Client::new()).await.unwrap();
+
+        let stored = store.token.lock().unwrap().clone().unwrap();
+        assert_eq!(stored.access_token, "access-1");
+        assert_eq!(stored.refresh_token, "refresh-1");
+        assert!(stored.expires_at >= before + Duration::from_secs(3599));
+    }
+
+    #[tokio::test]
+    async fn uses_token_from_store() {
+        let (server, tokens) = serve_tokens(3599);
+        let store = MemoryStore::default();
+        *store.token.lock().unwrap() = Some(StoredToken {
+            access_token: "stored".to_owned(),
+            expires_at: SystemTime::now() + Duration::from_secs(3599),
+            refresh_token: "stored-refresh".to_owned(),
+        });
+        let tokens = tokens.store(store);
+        let access_token = tokens.access_token(&reqwest::Client::new()).await.unwrap();
+        assert_eq!(access_token, "stored");
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn refreshes_stale_token_from_store_with_stored_refresh_token() {
+        let (server, tokens) = serve_tokens(3599);
+        let store = MemoryStore::default();
+        *store.token.lock().unwrap() = Some(StoredToken {
+            access_token: "stored".to_owned(),
+            expires_at: SystemTime::now(),
+            refresh_token: "stored-refresh".to_owned(),
+        });
+        let tokens = tokens.store(store);
+        let access_token = tokens.access_token(&reqwest::Client::new()).await.unwrap();
+        assert_eq!(access_token, "access-1");
+        assert_eq!(
+            server.requests()[0].form()["refresh_token"],
+            "stored-refresh"
+        );
+    }
+
+    #[tokio::test]
+    async fn keeps_token_when_saving_fails() {
+        let (server, tokens) = serve_tokens(3599);
+        let store = MemoryStore {
+            fail: true,
+            ..MemoryStore::default()
+        };
+        let tokens = tokens.store(store);
+        let http = reqwest::Client::new();
+        let error = tokens.access_token(&http).await.unwrap_err();
+        assert!(matches!(error, Error::TokenStore(_)));
+        assert_eq!(tokens.access_token(&http).await.unwrap(), "access-1");
+        assert_eq!(server.requests().len(), 1);
+    }
+}
@@ -0,0 +1,289 @@
+use std::{
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use serde::{Deserialize, Serialize};
+
+use super::{request_token, CachedToken, LoadError, TokenCache, TokenProvider, TOKEN_URI};
+use crate::{error::AuthError, Error, Result};
+
+/// Lifetime requested for each signed assertion. Google accepts at most one hour.
+const ASSERTION_LIFETIME: u64 = 3600;
+
+/// The JSON key file downloaded for a service account.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct ServiceAccountKey {
+    /// Email address of the service account.
+    pub client_email: String,
+    /// PEM-encoded RSA private key.
+    pub private_key: String,
+    /// Identifier of the private key, sent as the assertion's `kid`.
+    pub private_key_id: Option<String>,
+    /// Token endpoint to exchange assertions at.
+    #[serde(default = "default_token_uri")]
+    pub token_uri: String,
+}
+
+fn default_token_uri() -> String {
+    TOKEN_URI.to_owned()
+}
+
+impl std::fmt::Debug for ServiceAccountKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServiceAccountKey")
+            .field("client_email", &self.client_email)
+            .field("private_key_id", &self.private_key_id)
+            .field("token_uri", &self.token_uri)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Service account credentials, optionally impersonating a user through domain-wide delegation.
+///
+/// Access tokens are obtained with a signed JWT assertion and cached until shortly before they expire.
+#[allow(clippy::module_name_repetitions)]
+pub struct ServiceAccount {
+    client_email: String,
+    key_id: Option<String>,
+    key: EncodingKey,
+    token_uri: String,
+    scope: String,
+    subject: Option<String>,
+    cache: TokenCache,
+}
+
+impl ServiceAccount {
+    /// Create credentials from a parsed key, requesting `scopes`.
+    ///
+    /// # Errors
+    /// Errors if the private key is not a PEM-encoded RSA key.
+    pub fn new(key: &ServiceAccountKey, scopes: &[&str]) -> Result<Self, LoadError> {
+        Ok(Self {
+            client_email: key.client_email.clone(),
+            key_id: key.private_key_id.clone(),
+            key: EncodingKey::from_rsa_pem(key.private_key.as_bytes()).map_err(LoadError::Key)?,
+            token_uri: key.token_uri.clone(),
+            scope: scopes.join(" "),
+            subject: None,
+            cache: TokenCache::default(),
+        })
+    }
+
+    /// Create credentials from the contents of a JSON key file.
+    ///
+    /// # Errors
+    /// Errors if `json` is not a service account key, or the private key is invalid.
+    pub fn from_json(json: &str, scopes: &[&str]) -> Result<Self, LoadError> {
+        let key: ServiceAccountKey = serde_json::from_str(json).map_err(LoadError::Json)?;
+        Self::new(&key, scopes)
+    }
+
+    /// Create credentials from a JSON key file on disk.
+    ///
+    /// # Errors
+    /// Errors if the file cannot be read, is not a service account key, or the private key is invalid.
+    pub fn from_file(path: impl AsRef<Path>, scopes: &[&str]) -> Result<Self, LoadError> {
+        let json = std::fs::read_to_string(path).map_err(LoadError::Io)?;
+        Self::from_json(&json, scopes)
+    }
+
+    /// Impersonate `subject`, the email address of a user in the domain. The service account must have been granted
+    /// domain-wide delegation for the requested scopes.
+    #[must_use]
+    pub fn subject(mut self, subject: impl Into<String>) -> Self {
+        self.subject = Some(subject.into());
+        self.cache.clear();
+        self
+    }
+
+    /// Exchange assertions at `token_uri` instead of the one in the key file.
+    #[must_use]
+    pub fn token_uri(mut self, token_uri: impl Into<String>) -> Self {
+        self.token_uri = token_uri.into();
+        self.cache.clear();
+        self
+    }
+
+    /// Email address of the service account.
+    #[must_use]
+    pub fn client_email(&self) -> &str {
+        &self.client_email
+    }
+
+    fn assertion(&self) -> Result<String> {
+        let iat = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let claims = Claims {
+            iss: &self.client_email,
+            scope: &self.scope,
+            aud: &self.token_uri,
+            sub: self.subject.as_deref(),
+            iat,
+            exp: iat + ASSERTION_LIFETIME,
+        };
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid.clone_from(&self.key_id);
+        jsonwebtoken::encode(&header, &claims, &self.key).map_err(|source| {
+            Error::Auth(AuthError {
+                error: "invalid_key".to_owned(),
+                error_description: Some(source.to_string()),
+            })
+        })
+    }
+}
+
+/// Exchanges a new assertion whenever the cached access token is missing or about to expire.
+#[async_trait]
+impl TokenProvider for ServiceAccount {
+    async fn access_token(&self, http: &reqwest::Client) -> Result<String> {
+        if let Some(access_token) = self.cache.fresh().await {
+            return Ok(access_token);
+        }
+        let refreshing = self.cache.refreshing().await;
+        if let Some(access_token) = self.cache.fresh().await {
+            return Ok(access_token);
+        }
+        let assertion = self.assertion()?;
+        let response = request_token(
+            http,
+            &self.token_uri,
+            &[
+                ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+                ("assertion", &assertion),
+            ],
+        )
+        .await?;
+        let token = CachedToken::new(&response);
+        self.cache.insert(token.clone()).await;
+        drop(refreshing);
+        Ok(token.access_token)
+    }
+
+    fn impersonated(&self) -> Option<&str> {
+        self.subject.as_deref()
+    }
+}
+
+impl std::fmt::Debug for ServiceAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServiceAccount")
+            .field("client_email", &self.client_email)
+            .field("token_uri", &self.token_uri)
+            .field("scope", &self.scope)
+            .field("subject", &self.subject)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Serialize)]
+struct Claims<'a> {
+    iss: &'a str,
+    scope: &'a str,
+    aud: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sub: Option<&'a str>,
+    iat: u64,
+    exp: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use jsonwebtoken::{DecodingKey, Validation};
+
+    use super::*;
+    use crate::{auth::scopes, test_server::TestServer};
+
+    /// An RSA key generated for these tests only.
+    const PRIVATE_KEY: &str = include_str!("test_key.pem");
+
+    fn serve_tokens(expires_in: u64) -> (TestServer, ServiceAccount) {
+        let server = TestServer::start(move |_| {
+            let body = format!(
+                r#"{{"access_token": "ya29.a0", "expires_in": {expires_in}, "token_type": "Bearer"}}"#
+            );
+            (200, body)
+        });
+        let key = ServiceAccountKey {
+            client_email: "sync@project.iam.gserviceaccount.com".to_owned(),
+            private_key: PRIVATE_KEY.to_owned(),
+            private_key_id: Some("key-1".to_owned()),
+            token_uri: format!("{}/token", server.url()),
+        };
+        let account = ServiceAccount::new(&key, &[scopes::COURSES, scopes::ROSTERS]).unwrap();
+        (server, account)
+    }
+
+    /// The claims of `assertion`, without checking its signature.
+    fn claims(assertion: &str) -> serde_json::Value {
+        let mut validation = Validation::new(Algorithm::RS256);
+        validation.insecure_disable_signature_validation();
+        validation.validate_aud = false;
+        jsonwebtoken::decode(assertion, &DecodingKey::from_secret(&[]), &validation)
+            .unwrap()
+            .claims
+    }
+
+    #[tokio::test]
+    async fn exchanges_jwt_bearer_assertion() {
+        let (server, account) = serve_tokens(3599);
+        let account = account.subject("teacher@example.edu");
+        let access_token = account.access_token(&reqwest::Client::new()).await.unwrap();
+        assert_eq!(access_token, "ya29.a0");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].target, "/token");
+        let form = requests[0].form();
+        assert_eq!(
+            form["grant_type"],
+            "urn:ietf:params:oauth:grant-type:jwt-bearer"
+        );
+
+        let header = jsonwebtoken::decode_header(&form["assertion"]).unwrap();
+        assert_eq!(header.alg, Algorithm::RS256);
+        assert_eq!(header.kid.as_deref(), Some("key-1"));
+        let claims = claims(&form["assertion"]);
+        assert_eq!(claims["iss"], "sync@project.iam.gserviceaccount.com");
+        assert_eq!(
+            claims["scope"],
+            format!("{} {}", scopes::COURSES, scopes::ROSTERS)
+        );
+        assert_eq!(claims["aud"], format!("{}/token", server.url()));
+        assert_eq!(claims["sub"], "teacher@example.edu");
+        let lifetime = claims["exp"].as_u64().unwrap() - claims["iat"].as_u64().unwrap();
+        assert_eq!(lifetime, ASSERTION_LIFETIME);
+    }
+
+    #[tokio::test]
+    async fn omits_subject_without_impersonation() {
+        let (server, account) = serve_tokens(3599);
+        account.access_token(&reqwest::Client::new()).await.unwrap();
+        let claims = claims(&server.requests()[0].form()["assertion"]);
+        assert!(claims.get("sub").is_none());
+    }
+
+    #[tokio::test]
+    async fn caches_token() {
+        let (server, account) = serve_tokens(3599);
+        let http = reqwest::Client::new();
+        account.access_token(&http).await.unwrap();
+        account.access_token(&http).await.unwrap();
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn replaces_token_about_to_expire() {
+        let (server, account) = serve_tokens(30);
+        let http = reqwest::Client::new();
+        account.access_token(&http).await.unwrap();
+        account.access_token(&http).await.unwrap();
+        assert_eq!(server.requests().len(), 2);
+    }
+}
@@ -0,0 +1,400 @@
+use std::{
+    collections::hash_map::RandomState, hash::BuildHasher, marker::PhantomData, time::SystemTime,
+};
+
+use reqwest::header::CONTENT_TYPE;
+use serde::de::DeserializeOwned;
+
+use super::{
+    call::{authorize, decode},
+    Call, Client,
+};
+use crate::{error::ApiError, Error, Result};
+
+/// The most calls Google APIs accept in one batch request.
+const MAX_CALLS: usize = 50;
+
+impl Client {
+    /// Start a batch of calls which all resolve to a `T`, such as adding many students to a course.
+    pub const fn batch<T>(&self) -> Batch<'_, T> {
+        Batch {
+            client: self,
+            requests: Vec::new(),
+            response: PhantomData,
+        }
+    }
+}
+
+/// Many calls sent together in `multipart/mixed` requests to the batch endpoint, created by [`Client::batch`].
+///
+/// Calls are sent in requests of up to 50, and each still counts against quota on its own. The calls in a batch may
+/// be applied in any order, and are not retried.
+#[must_use = "batches do nothing unless sent"]
+pub struct Batch<'a, T> {
+    client: &'a Client,
+    requests: Vec<reqwest::Result<reqwest::Request>>,
+    response: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> Batch<'a, T> {
+    /// Add a call to the batch. The call must have been created by the same client as the batch.
+    pub fn push(&mut self, call: Call<'a, T>) {
+        self.requests.push(call.build());
+    }
+
+    /// The number of calls in the batch.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Whether the batch has no calls.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+}
+
+impl<'a, T> Extend<Call<'a, T>> for Batch<'a, T> {
+    fn extend<I: IntoIterator<Item = Call<'a, T>>>(&mut self, calls: I) {
+        self.requests.extend(calls.into_iter().map(Call::build));
+    }
+}
+
+impl<T: DeserializeOwned> Batch<'_, T> {
+    /// Send every call in the batch, returning the result of each call in the order they were added.
+    ///
+    /// If a batch request could not be sent or was rejected as a whole, every call in that request fails with the
+    /// same error, and the remaining requests are still sent.
+    pub async fn send(self) -> Vec<Result<T>> {
+        let client = self.client;
+        let mut results: Vec<Option<Result<T>>> = Vec::with_capacity(self.requests.len());
+        let mut pending = Vec::new();
+        for (index, request) in self.requests.into_iter().enumerate() {
+            match request {
+                Ok(request) => {
+                    pending.push((index, request));
+                    results.push(None);
+                }
+                Err(error) => results.push(Some(Err(error.into()))),
+            }
+        }
+        for chunk in pending.chunks(MAX_CALLS) {
+            let requests: Vec<_> = chunk.iter().map(|(_, request)| request).collect();
+            match send_chunk::<T>(client, &requests).await {
+                Ok(mut responses) => {
+                    for (position, (index, _)) in chunk.iter().enumerate() {
+                        results[*index] = responses[position].take();
+                    }
+                }
+                Err(error) => {
+                    for (index, _) in chunk {
+                        results[*index] = Some(Err(chunk_error(&error)));
+                    }
+                }
+            }
+        }
+        results
+            .into_iter()
+            .map(|result| {
+                result.unwrap_or_else(|| Err(Error::Batch("no response for call".to_string())))
+            })
+            .collect()
+    }
+}
+
+impl<T> std::fmt::Debug for Batch<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Batch")
+            .field("len", &self.requests.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Send one batch request of up to [`MAX_CALLS`] calls. The response to each call is at its position in `requests`.
+async fn send_chunk<T: DeserializeOwned>(
+    client: &Client,
+    requests: &[&reqwest::Request],
+) -> Result<Vec<Option<Result<T>>>> {
+    let boundary = format!(
+        "batch_{:016x}",
+        RandomState::new().hash_one(SystemTime::now())
+    );
+    let mut body = Vec::new();
+    for (id, request) in requests.iter().enumerate() {
+        client.throttle(request.method()).await;
+        write_part(&mut body, &boundary, id, request);
+    }
+    body.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+
+    let mut url = client.base_url.clone();
+    if let Ok(mut segments) = url.path_segments_mut() {
+        segments.pop().push("batch");
+    }
+    let mut request = client
+        .http
+        .post(url)
+        .header(
+            CONTENT_TYPE,
+            format!("multipart/mixed; boundary={boundary}"),
+        )
+        .body(body)
+        .build()?;
+    authorize(client, &mut request).await?;
+    let response = client.http.execute(request).await?;
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let body = response.text().await?;
+    if !status.is_success() {
+        return Err(ApiError::from_response(status.as_u16(), &body).into());
+    }
+
+    Ok(responses(
+        parse_parts(&content_type, &body)?,
+        requests.len(),
+    ))
+}
+
+/// Match the parts of a batch response to the `len` calls in its request. Calls without a part are left as [`None`].
+fn responses<T: DeserializeOwned>(parts: Vec<Part>, len: usize) -> Vec<Option<Result<T>>> {
+    let mut responses: Vec<Option<Result<T>>> = (0..len).map(|_| None).collect();
+    for part in parts {
+        let slot = part.id.and_then(|id| responses.get_mut(id));
+        if let Some(slot) = slot {
+            *slot = Some(decode(part.status, part.body));
+        }
+    }
+    responses
+}
+
+/// The error for one call in a batch request that failed as a whole. API errors are kept, so that checks such as
+/// [`Error::is_rate_limited`] still work; anything else is described in an [`Error::Batch`].
+fn chunk_error(error: &Error) -> Error {
+    match error {
+        Error::Api(error) => Error::Api(error.clone()),
+        error => Error::Batch(format!("request failed: {error}")),
+    }
+}
+
+/// Append `request` to a batch body, as an `application/http` part.
+fn write_part(body: &mut Vec<u8>, boundary: &str, id: usize, request: &reqwest::Request) {
+    let url = request.url();
+    let target = url.query().map_or_else(
+        || url.path().to_string(),
+        |query| format!("{}?{query}", url.path()),
+    );
+    let mut head = format!(
+        "--{boundary}\r\nContent-Type: application/http\r\nContent-ID: <item{id}>\r\n\r\n{} {target} HTTP/1.1\r\n",
+        request.method()
+    );
+    for (name, value) in request.headers() {
+        if let Ok(value) = value.to_str() {
+            head.push_str(name.as_str());
+            head.push_str(": ");
+            head.push_str(value);
+            head.push_str("\r\n");
+        }
+    }
+    let content = request
+        .body()
+        .and_then(reqwest::Body::as_bytes)
+        .unwrap_or_default();
+    if !content.is_empty() {
+        head.push_str("Content-Length: ");
+        head.push_str(&content.len().to_string());
+        head.push_str("\r\n");
+    }
+    head.push_str("\r\n");
+    body.extend_from_slice(head.as_bytes());
+    body.extend_from_slice(content);
+    body.extend_from_slice(b"\r\n");
+}
+
+/// The response to one call in a batch.
+struct Part {
+    /// Position of the call in its batch request, from the part's `Content-ID`.
+    id: Option<usize>,
+    status: u16,
+    body: String,
+}
+
+/// Split a `multipart/mixed` batch response into the response to each call.
+fn parse_parts(content_type: &str, body: &str) -> Result<Vec<Part>> {
+    let boundary = content_type
+        .split(';')
+        .find_map(|param| param.trim().strip_prefix("boundary="))
+        .map(|boundary| boundary.trim_matches('"'))
+        .ok_or_else(|| Error::Batch(format!("not a multipart response: {content_type}")))?;
+    let delimiter = format!("--{boundary}");
+    let mut parts = Vec::new();
+    for part in body.split(delimiter.as_str()).skip(1) {
+        if part.starts_with("--") {
+            break;
+        }
+        let (headers, http) = split_head(part.trim_start())
+            .ok_or_else(|| Error::Batch("part has no body".to_string()))?;
+        let id = headers.lines().find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            if !name.trim().eq_ignore_ascii_case("content-id") {
+                return None;
+            }
+            value
+                .trim()
+                .trim_start_matches('<')
+                .trim_end_matches('>')
+                .strip_prefix("response-item")?
+                .parse()
+                .ok()
+        });
+        let (head, content) =
+            split_head(http).ok_or_else(|| Error::Batch("part has no response".to_string()))?;
+        let status = head
+            .lines()
+            .next()
+            .and_then(|line| line.split_whitespace().nth(1))
+            .and_then(|code| code.parse().ok())
+            .ok_or_else(|| Error::Batch(format!("invalid status line in: {head}")))?;
+        parts.push(Part {
+            id,
+            status,
+            body: content.trim_end_matches(['\r', '\n']).to_string(),
+        });
+    }
+    Ok(parts)
+}
+
+/// Split text at the first blank line, into headers and the rest.
+fn split_head(text: &str) -> Option<(&str, &str)> {
+    text.split_once("\r\n\r\n")
+        .or_else(|| text.split_once("\n\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Deserialize, Debug, PartialEq, Eq)]
+    struct Item {
+        id: String,
+    }
+
+    fn part(id: &str, status: &str, body: &str) -> String {
+        format!(
+            "Content-Type: application/http\r\nContent-ID: <{id}>\r\n\r\nHTTP/1.1 {status}\r\nContent-Type: application/json\r\n\r\n{body}\r\n"
+        )
+    }
+
+    fn ids(responses: Vec<Option<Result<Item>>>) -> Vec<Option<String>> {
+        responses
+            .into_iter()
+            .map(|response| {
+                response.map(|result| result.map_or_else(|error| error.to_string(), |item| item.id))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn maps_out_of_order_parts_to_their_calls() {
+        let body = format!(
+            "--b\r\n{}--b\r\n{}--b\r\n{}--b--\r\n",
+            part("response-item2", "200 OK", r#"{"id":"c"}"#),
+            part("response-item0", "200 OK", r#"{"id":"a"}"#),
+            part("response-item1", "200 OK", r#"{"id":"b"}"#),
+        );
+        let parts = parse_parts("multipart/mixed; boundary=b", &body).unwrap();
+        assert_eq!(
+            ids(responses(parts, 3)),
+            [
+                Some("a".to_string()),
+                Some("b".to_string()),
+                Some("c".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_quoted_boundary() {
+        let body = format!(
+            "--batch_x\r\n{}--batch_x--\r\n",
+            part("response-item0", "200 OK", "{}")
+        );
+        let parts = parse_parts(r#"multipart/mixed; boundary="batch_x""#, &body).unwrap();
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].id, Some(0));
+        assert_eq!(parts[0].status, 200);
+        assert_eq!(parts[0].body, "{}");
+    }
+
+    #[test]
+    fn reads_lf_line_endings() {
+        let body = format!(
+            "--b\r\n{}--b--\r\n",
+            part(
+                "response-item0",
+                "404 Not Found",
+                r#"{"error":{"code":404,"message":"gone"}}"#
+            )
+        )
+        .replace("\r\n", "\n");
+        let parts = parse_parts("multipart/mixed; boundary=b", &body).unwrap();
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].id, Some(0));
+        assert_eq!(parts[0].status, 404);
+        let responses = responses::<Item>(parts, 1);
+        assert!(matches!(&responses[0], Some(Err(error)) if error.is_not_found()));
+    }
+
+    #[test]
+    fn leaves_calls_without_a_part_empty() {
+        let body = format!(
+            "--b\r\n{}--b\r\n{}--b--\r\n",
+            part("response-item0", "200 OK", r#"{"id":"a"}"#),
+            part("response-item2", "200 OK", r#"{"id":"c"}"#),
+        );
+        let parts = parse_parts("multipart/mixed; boundary=b", &body).unwrap();
+        assert_eq!(
+            ids(responses(parts, 3)),
+            [Some("a".to_string()), None, Some("c".to_string())]
+        );
+    }
+
+    #[test]
+    fn rejects_non_multipart_response() {
+        assert!(matches!(
+            parse_parts("application/json", "{}"),
+            Err(Error::Batch(_))
+        ));
+    }
+
+    #[test]
+    fn writes_request_as_http_part() {
+        let mut request = reqwest::Request::new(
+            reqwest::Method::POST,
+            "https://classroom.googleapis.com/v1/courses/1/students?enrollmentCode=x"
+                .parse()
+                .unwrap(),
+        );
+        *request.body_mut() = Some(r#"{"userId":"me"}"#.into());
+        let mut body = Vec::new();
+        write_part(&mut body, "b", 3, &request);
+        assert_eq!(
+            String::from_utf8(body).unwrap(),
+            "--b\r\nContent-Type: application/http\r\nContent-ID: <item3>\r\n\r\n\
+             POST /v1/courses/1/students?enrollmentCode=x HTTP/1.1\r\nContent-Length: 15\r\n\r\n\
+             {\"userId\":\"me\"}\r\n"
+        );
+    }
+
+    #[test]
+    fn keeps_api_errors_for_failed_requests() {
+        let error = Error::Api(ApiError::from_response(429, "rate limited"));
+        assert!(chunk_error(&error).is_rate_limited());
+    }
+}
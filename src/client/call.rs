@@ -0,0 +1,181 @@
+use std::{
+    future::{Future, IntoFuture},
+    marker::PhantomData,
+    pin::Pin,
+    time::Duration,
+};
+
+use reqwest::header::{HeaderValue, AUTHORIZATION};
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{retry::retry_after, Client};
+use crate::{
+    error::{ApiError, AuthError},
+    Error, Result,
+};
+
+/// A single pending API call which resolves to a `T`.
+///
+/// Calls do nothing until they are awaited or [`Call::send`] is called.
+#[must_use = "calls do nothing unless awaited"]
+pub struct Call<'a, T> {
+    client: &'a Client,
+    request: reqwest::RequestBuilder,
+    idempotent: bool,
+    response: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> Call<'a, T> {
+    pub(crate) const fn new(client: &'a Client, request: reqwest::RequestBuilder) -> Self {
+        Self {
+            client,
+            request,
+            idempotent: false,
+            response: PhantomData,
+        }
+    }
+
+    /// Copy this call so it can be sent more than once.
+    pub(crate) fn try_clone(&self) -> Option<Self> {
+        Some(Self {
+            idempotent: self.idempotent,
+            ..Self::new(self.client, self.request.try_clone()?)
+        })
+    }
+
+    /// Build the request, without an access token.
+    pub(crate) fn build(self) -> reqwest::Result<reqwest::Request> {
+        self.request.build()
+    }
+
+    /// Allow this call to be retried by the client's [`RetryPolicy`](super::RetryPolicy) even though its method is
+    /// not idempotent, because applying it twice has the same effect as applying it once.
+    pub const fn idempotent(mut self) -> Self {
+        self.idempotent = true;
+        self
+    }
+
+    /// Append query parameters to the request.
+    pub fn query<Q: Serialize + ?Sized>(mut self, query: &Q) -> Self {
+        self.request = self.request.query(query);
+        self
+    }
+
+    /// Send `body` as the JSON request body.
+    pub fn json<B: Serialize + ?Sized>(mut self, body: &B) -> Self {
+        self.request = self.request.json(body);
+        self
+    }
+}
+
+impl<T: DeserializeOwned> Call<'_, T> {
+    /// Send the request and deserialize the response, retrying failures as allowed by the client's
+    /// [`RetryPolicy`](super::RetryPolicy).
+    ///
+    /// # Errors
+    /// Errors if no access token could be obtained, the request could not be sent, the API responded with an error, or the response body did not match
+    /// `T`. When the call was retried, this is the error from the last attempt.
+    pub async fn send(self) -> Result<T> {
+        let client = self.client;
+        let policy = &client.retry;
+        let request = self.request.build()?;
+        let retry = policy.allows(request.method(), self.idempotent);
+        let mut attempt = 1;
+        loop {
+            let copy = if retry { request.try_clone() } else { None };
+            let Some(current) = copy else {
+                return execute(client, request).await.map_err(|(error, _)| error);
+            };
+            let (error, retry_after) = match execute(client, current).await {
+                Ok(value) => return Ok(value),
+                Err(failure) => failure,
+            };
+            let Some(delay) = policy.delay(attempt, &error, retry_after) else {
+                return Err(error);
+            };
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Send a single attempt of a call. Failures carry the delay requested by the response's `Retry-After` header.
+async fn execute<T: DeserializeOwned>(
+    client: &Client,
+    mut request: reqwest::Request,
+) -> Result<T, (Error, Option<Duration>)> {
+    client.throttle(request.method()).await;
+    authorize(client, &mut request)
+        .await
+        .map_err(|error| (error, None))?;
+    let response = client
+        .http
+        .execute(request)
+        .await
+        .map_err(|error| (error.into(), None))?;
+    let status = response.status();
+    let retry_after = retry_after(response.headers());
+    let body = response
+        .text()
+        .await
+        .map_err(|error| (error.into(), None))?;
+    decode(status.as_u16(), body).map_err(|error| match error {
+        Error::Api(_) => (error, retry_after),
+        _ => (error, None),
+    })
+}
+
+/// Attach the client's access token to `request`.
+pub async fn authorize(client: &Client, request: &mut reqwest::Request) -> Result<()> {
+    let token = client.access_token().await?;
+    let mut authorization = HeaderValue::try_from(format!("Bearer {token}")).map_err(|_| {
+        Error::Auth(AuthError {
+            error: "invalid_token".to_string(),
+            error_description: Some("access token is not a valid header value".to_string()),
+        })
+    })?;
+    authorization.set_sensitive(true);
+    request.headers_mut().insert(AUTHORIZATION, authorization);
+    Ok(())
+}
+
+/// Deserialize a response body, or the error it describes if `status` is not a success.
+pub fn decode<T: DeserializeOwned>(status: u16, body: String) -> Result<T> {
+    if !(200..300).contains(&status) {
+        return Err(ApiError::from_response(status, &body).into());
+    }
+    serde_json::from_str(&body).map_err(|source| Error::Decode { source, body })
+}
+
+impl<'a, T: DeserializeOwned + 'a> IntoFuture for Call<'a, T> {
+    type Output = Result<T>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(self.send())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Status;
+
+    #[test]
+    fn decodes_success() {
+        assert_eq!(decode::<Vec<u32>>(200, "[1, 2]".into()).unwrap(), [1, 2]);
+    }
+
+    #[test]
+    fn keeps_body_of_undecodable_success() {
+        let error = decode::<Vec<u32>>(200, "<html>".into()).unwrap_err();
+        assert!(matches!(error, Error::Decode { body, .. } if body == "<html>"));
+    }
+
+    #[test]
+    fn decodes_error_status_as_api_error() {
+        let error = decode::<Vec<u32>>(404, "Not Found".into()).unwrap_err();
+        assert_eq!(error.api().unwrap().status, Status::NotFound);
+        assert!(error.is_not_found());
+    }
+}
@@ -0,0 +1,287 @@
+use std::future::IntoFuture;
+
+use reqwest::Method;
+
+use crate::{
+    client::{Call, Client, List},
+    error::Result,
+    model::{
+        courses::add_ons::{
+            AddOnAttachment, AddOnAttachmentCreate, AddOnAttachmentModify,
+            AddOnAttachmentStudentSubmission, AddOnAttachmentStudentSubmissionModify, AddOnContext,
+            ListAddOnAttachmentsResponse,
+        },
+        Empty,
+    },
+};
+
+/// Operations on the add-on attachments of one post.
+///
+/// Created by [`CourseWorks::add_on_attachments`](super::course_work::CourseWorks::add_on_attachments),
+/// [`Announcements::add_on_attachments`](super::announcements::Announcements::add_on_attachments) or
+/// [`CourseWorkMaterials::add_on_attachments`](super::course_work::materials::CourseWorkMaterials::add_on_attachments).
+#[derive(Debug, Clone)]
+pub struct AddOnAttachments<'a> {
+    client: &'a Client,
+    course_id: String,
+    collection: &'static str,
+    item_id: String,
+}
+
+impl<'a> AddOnAttachments<'a> {
+    pub(crate) fn new(
+        client: &'a Client,
+        course_id: &str,
+        collection: &'static str,
+        item_id: String,
+    ) -> Self {
+        Self {
+            client,
+            course_id: course_id.to_string(),
+            collection,
+            item_id,
+        }
+    }
+
+    /// Get the add-on context of the post for the requesting user. Set [`Call::add_on_token`] when the add-on was
+    /// launched in the Classroom UI.
+    pub fn context(&self) -> Call<'a, AddOnContext> {
+        self.client.request(
+            Method::GET,
+            &[
+                "courses",
+                &self.course_id,
+                self.collection,
+                &self.item_id,
+                "addOnContext",
+            ],
+        )
+    }
+
+    /// Create an add-on attachment. Set [`CreateAddOnAttachment::add_on_token`] when the add-on was launched in the
+    /// Classroom UI.
+    pub fn create(&self, attachment: &AddOnAttachmentCreate) -> CreateAddOnAttachment<'a> {
+        CreateAddOnAttachment {
+            call: self
+                .client
+                .request(
+                    Method::POST,
+                    &[
+                        "courses",
+                        &self.course_id,
+                        self.collection,
+                        &self.item_id,
+                        "addOnAttachments",
+                    ],
+                )
+                .json(attachment),
+        }
+    }
+
+    /// Get an add-on attachment.
+    pub fn get(&self, id: &str) -> Call<'a, AddOnAttachment> {
+        self.call(Method::GET, id)
+    }
+
+    /// List the add-on attachments of the post that were created by the requesting add-on.
+    pub fn list(&self) -> List<'a, ListAddOnAttachmentsResponse> {
+        List::new(self.client.request(
+            Method::GET,
+            &[
+                "courses",
+                &self.course_id,
+                self.collection,
+                &self.item_id,
+                "addOnAttachments",
+            ],
+        ))
+    }
+
+    /// Update the fields of an add-on attachment which are set in `attachment`.
+    pub fn patch(&self, id: &str, attachment: &AddOnAttachmentModify) -> Call<'a, AddOnAttachment> {
+        self.call(Method::PATCH, id)
+            .query(&[("updateMask", attachment.update_mask())])
+            .json(attachment)
+    }
+
+    /// Delete an add-on attachment.
+    pub fn delete(&self, id: &str) -> Call<'a, Empty> {
+        self.call(Method::DELETE, id)
+    }
+
+    fn call<T>(&self, method: Method, id: &str) -> Call<'a, T> {
+        self.client.request(
+            method,
+            &[
+                "courses",
+                &self.course_id,
+                self.collection,
+                &self.item_id,
+                "addOnAttachments",
+                id,
+            ],
+        )
+    }
+}
+
+/// A pending call to create an add-on attachment, from [`AddOnAttachments::create`].
+///
+/// Like [`Call`], this does nothing until it is awaited or [`CreateAddOnAttachment::send`] is called.
+#[must_use = "calls do nothing unless awaited"]
+pub struct CreateAddOnAttachment<'a> {
+    call: Call<'a, AddOnAttachment>,
+}
+
+impl CreateAddOnAttachment<'_> {
+    /// The token from the `addOnToken` query parameter Classroom launched the add-on with.
+    pub fn add_on_token(self, token: &str) -> Self {
+        Self {
+            call: self.call.query(&[("addOnToken", token)]),
+        }
+    }
+
+    /// Send the request.
+    ///
+    /// # Errors
+    /// Errors if the request fails, see [`Call::send`].
+    pub async fn send(self) -> Result<AddOnAttachment> {
+        self.call.send().await
+    }
+}
+
+impl<'a> IntoFuture for CreateAddOnAttachment<'a> {
+    type Output = Result<AddOnAttachment>;
+    type IntoFuture = <Call<'a, AddOnAttachment> as IntoFuture>::IntoFuture;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.call.into_future()
+    }
+}
+
+/// Operations on `courses.courseWork.addOnAttachments.studentSubmissions`, created by
+/// [`CourseWorks::add_on_student_submissions`](super::course_work::CourseWorks::add_on_student_submissions).
+#[derive(Debug, Clone)]
+pub struct AddOnStudentSubmissions<'a> {
+    client: &'a Client,
+    course_id: String,
+    item_id: String,
+    attachment_id: String,
+}
+
+impl<'a> AddOnStudentSubmissions<'a> {
+    pub(crate) fn new(
+        client: &'a Client,
+        course_id: &str,
+        item_id: String,
+        attachment_id: String,
+    ) -> Self {
+        Self {
+            client,
+            course_id: course_id.to_string(),
+            item_id,
+            attachment_id,
+        }
+    }
+
+    /// Get a student submission for the attachment.
+    pub fn get(&self, id: &str) -> Call<'a, AddOnAttachmentStudentSubmission> {
+        self.call(Method::GET, id)
+    }
+
+    /// Update the grade of a student submission for the attachment, passing it back to Classroom.
+    pub fn patch(
+        &self,
+        id: &str,
+        submission: &AddOnAttachmentStudentSubmissionModify,
+    ) -> Call<'a, AddOnAttachmentStudentSubmission> {
+        self.call(Method::PATCH, id)
+            .query(&[("updateMask", submission.update_mask())])
+            .json(submission)
+    }
+
+    fn call<T>(&self, method: Method, id: &str) -> Call<'a, T> {
+        self.client.request(
+            method,
+            &[
+                "courses",
+                &self.course_id,
+                "courseWork",
+                &self.item_id,
+                "addOnAttachments",
+                &self.attachment_id,
+                "studentSubmissions",
+                id,
+            ],
+        )
+    }
+}
+
+impl Call<'_, AddOnContext> {
+    /// The token from the `addOnToken` query parameter Classroom launched the add-on with.
+    pub fn add_on_token(self, token: &str) -> Self {
+        self.query(&[("addOnToken", token)])
+    }
+
+    /// Only return context for this attachment. Required when the requesting user is a student.
+    pub fn attachment_id(self, attachment_id: &str) -> Self {
+        self.query(&[("attachmentId", attachment_id)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        auth::StaticToken,
+        model::courses::add_ons::{AddOnAttachmentCreate, EmbedUri},
+        test_server::TestServer,
+        Client,
+    };
+
+    #[tokio::test]
+    async fn creates_attachment_with_add_on_token() {
+        let server = TestServer::start(|_| {
+            let body = r#"{
+                "courseId": "1",
+                "itemId": "2",
+                "id": "3",
+                "title": "Quiz",
+                "teacherViewUri": { "uri": "https://example.com/teacher" },
+                "studentViewUri": { "uri": "https://example.com/student" }
+            }"#;
+            (200, body.to_string())
+        });
+        let client = Client::builder(StaticToken::new("token"))
+            .endpoint(server.url().parse().unwrap())
+            .build();
+        let uri = |uri: &str| EmbedUri {
+            uri: uri.to_string(),
+        };
+        let attachment = AddOnAttachmentCreate {
+            title: "Quiz".to_string(),
+            teacher_view_uri: uri("https://example.com/teacher"),
+            student_view_uri: uri("https://example.com/student"),
+            student_work_review_uri: None,
+            due_date: None,
+            due_time: None,
+            max_points: None,
+        };
+        let created = client
+            .courses()
+            .course_work("1")
+            .add_on_attachments("2")
+            .create(&attachment)
+            .add_on_token("launch-token")
+            .await
+            .unwrap();
+        assert_eq!(created.id, "3");
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(
+            requests[0].target,
+            "/v1/courses/1/courseWork/2/addOnAttachments?addOnToken=launch-token"
+        );
+        let body: serde_json::Value = serde_json::from_str(&requests[0].body).unwrap();
+        assert_eq!(body["title"], "Quiz");
+    }
+}
@@ -0,0 +1,64 @@
+use reqwest::Method;
+
+use super::Courses;
+use crate::{
+    client::{Call, Client, List},
+    model::{
+        courses::aliases::{AliasScope, CourseAlias, ListCourseAliasesResponse},
+        Empty,
+    },
+};
+
+impl<'a> Courses<'a> {
+    /// Operations on the aliases of a course.
+    #[must_use]
+    pub fn aliases(&self, course_id: impl Into<AliasScope>) -> Aliases<'a> {
+        Aliases {
+            client: self.client,
+            course_id: course_id.into().to_string(),
+        }
+    }
+}
+
+/// Operations on `courses.aliases`, created by [`Courses::aliases`].
+#[derive(Debug, Clone)]
+pub struct Aliases<'a> {
+    client: &'a Client,
+    course_id: String,
+}
+
+impl<'a> Aliases<'a> {
+    /// Create a domain-scoped alias `d:<name>` for the course. Only domain administrators may create these. Fails
+    /// with `ALREADY_EXISTS` if the alias is taken, see [`Error::is_already_exists`](crate::Error::is_already_exists).
+    pub fn create_domain(&self, name: &str) -> Call<'a, CourseAlias> {
+        self.create(AliasScope::Domain(name.to_string()))
+    }
+
+    /// Create a project-scoped alias `p:<name>` for the course. Fails with `ALREADY_EXISTS` if the alias is taken,
+    /// see [`Error::is_already_exists`](crate::Error::is_already_exists).
+    pub fn create_project(&self, name: &str) -> Call<'a, CourseAlias> {
+        self.create(AliasScope::Project(name.to_string()))
+    }
+
+    fn create(&self, alias: AliasScope) -> Call<'a, CourseAlias> {
+        self.client
+            .request(Method::POST, &["courses", &self.course_id, "aliases"])
+            .json(&CourseAlias { alias })
+    }
+
+    /// List the aliases of the course.
+    pub fn list(&self) -> List<'a, ListCourseAliasesResponse> {
+        List::new(
+            self.client
+                .request(Method::GET, &["courses", &self.course_id, "aliases"]),
+        )
+    }
+
+    /// Delete an alias of the course.
+    pub fn delete(&self, alias: &AliasScope) -> Call<'a, Empty> {
+        self.client.request(
+            Method::DELETE,
+            &["courses", &self.course_id, "aliases", &alias.to_string()],
+        )
+    }
+}
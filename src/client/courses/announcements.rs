@@ -0,0 +1,125 @@
+use reqwest::Method;
+
+use super::{add_ons::AddOnAttachments, Courses};
+use crate::{
+    client::{Call, Client, List},
+    model::{
+        courses::{
+            aliases::AliasScope,
+            announcements::{
+                Announcement, AnnouncementCreate, AnnouncementModify, AnnouncementState,
+                ListAnnouncementsResponse,
+            },
+        },
+        Empty, ModifyAssignees,
+    },
+};
+
+impl<'a> Courses<'a> {
+    /// Operations on the announcements of a course.
+    #[must_use]
+    pub fn announcements(&self, course_id: impl Into<AliasScope>) -> Announcements<'a> {
+        Announcements {
+            client: self.client,
+            course_id: course_id.into().to_string(),
+        }
+    }
+}
+
+/// Operations on `courses.announcements`, created by [`Courses::announcements`].
+#[derive(Debug, Clone)]
+pub struct Announcements<'a> {
+    client: &'a Client,
+    course_id: String,
+}
+
+impl<'a> Announcements<'a> {
+    /// Create an announcement.
+    pub fn create(&self, announcement: &AnnouncementCreate) -> Call<'a, Announcement> {
+        self.client
+            .request(Method::POST, &["courses", &self.course_id, "announcements"])
+            .json(announcement)
+    }
+
+    /// Get an announcement.
+    pub fn get(&self, id: &str) -> Call<'a, Announcement> {
+        self.client.request(
+            Method::GET,
+            &["courses", &self.course_id, "announcements", id],
+        )
+    }
+
+    /// Operations on the add-on attachments of an announcement.
+    #[must_use]
+    pub fn add_on_attachments(&self, item_id: impl Into<String>) -> AddOnAttachments<'a> {
+        AddOnAttachments::new(
+            self.client,
+            &self.course_id,
+            "announcements",
+            item_id.into(),
+        )
+    }
+
+    /// List the announcements the requesting user can view.
+    pub fn list(&self) -> List<'a, ListAnnouncementsResponse> {
+        List::new(
+            self.client
+                .request(Method::GET, &["courses", &self.course_id, "announcements"]),
+        )
+    }
+
+    /// Update the fields of an announcement which are set in `announcement`.
+    pub fn patch(&self, id: &str, announcement: &AnnouncementModify) -> Call<'a, Announcement> {
+        self.client
+            .request(
+                Method::PATCH,
+                &["courses", &self.course_id, "announcements", id],
+            )
+            .query(&[("updateMask", announcement.update_mask())])
+            .json(announcement)
+    }
+
+    /// Delete an announcement.
+    pub fn delete(&self, id: &str) -> Call<'a, Empty> {
+        self.client.request(
+            Method::DELETE,
+            &["courses", &self.course_id, "announcements", id],
+        )
+    }
+
+    /// Change which students can see an announcement.
+    pub fn modify_assignees(
+        &self,
+        id: &str,
+        assignees: &ModifyAssignees,
+    ) -> Call<'a, Announcement> {
+        self.client
+            .request(
+                Method::POST,
+                &[
+                    "courses",
+                    &self.course_id,
+                    "announcements",
+                    &format!("{id}:modifyAssignees"),
+                ],
+            )
+            .json(assignees)
+    }
+}
+
+impl List<'_, ListAnnouncementsResponse> {
+    /// Only return announcements in one of these states. If unset, only [`AnnouncementState::Published`]
+    /// announcements are returned.
+    pub fn announcement_states(self, announcement_states: &[AnnouncementState]) -> Self {
+        let query: Vec<_> = announcement_states
+            .iter()
+            .map(|state| ("announcementStates", state))
+            .collect();
+        self.query(&query)
+    }
+
+    /// Sort results by `updateTime`, for example `updateTime desc`. Defaults to descending.
+    pub fn order_by(self, order_by: &str) -> Self {
+        self.query(&[("orderBy", order_by)])
+    }
+}
@@ -0,0 +1,128 @@
+use reqwest::Method;
+
+use crate::{
+    client::{
+        courses::{add_ons::AddOnAttachments, Courses},
+        Call, Client, List,
+    },
+    model::{
+        courses::{
+            aliases::AliasScope,
+            course_work::materials::{
+                CourseWorkMaterial, CourseWorkMaterialCreate, CourseWorkMaterialModify,
+                CourseWorkMaterialState, ListCourseWorkMaterialResponse,
+            },
+        },
+        Empty,
+    },
+};
+
+impl<'a> Courses<'a> {
+    /// Operations on the course work materials of a course.
+    #[must_use]
+    pub fn course_work_materials(
+        &self,
+        course_id: impl Into<AliasScope>,
+    ) -> CourseWorkMaterials<'a> {
+        CourseWorkMaterials {
+            client: self.client,
+            course_id: course_id.into().to_string(),
+        }
+    }
+}
+
+/// Operations on `courses.courseWorkMaterials`, created by [`Courses::course_work_materials`].
+#[derive(Debug, Clone)]
+pub struct CourseWorkMaterials<'a> {
+    client: &'a Client,
+    course_id: String,
+}
+
+impl<'a> CourseWorkMaterials<'a> {
+    /// Create a course work material.
+    pub fn create(&self, material: &CourseWorkMaterialCreate) -> Call<'a, CourseWorkMaterial> {
+        self.client
+            .request(
+                Method::POST,
+                &["courses", &self.course_id, "courseWorkMaterials"],
+            )
+            .json(material)
+    }
+
+    /// Get a course work material.
+    pub fn get(&self, id: &str) -> Call<'a, CourseWorkMaterial> {
+        self.client.request(
+            Method::GET,
+            &["courses", &self.course_id, "courseWorkMaterials", id],
+        )
+    }
+
+    /// Operations on the add-on attachments of a course work material.
+    #[must_use]
+    pub fn add_on_attachments(&self, item_id: impl Into<String>) -> AddOnAttachments<'a> {
+        AddOnAttachments::new(
+            self.client,
+            &self.course_id,
+            "courseWorkMaterials",
+            item_id.into(),
+        )
+    }
+
+    /// List the course work materials the requesting user can view.
+    pub fn list(&self) -> List<'a, ListCourseWorkMaterialResponse> {
+        List::new(self.client.request(
+            Method::GET,
+            &["courses", &self.course_id, "courseWorkMaterials"],
+        ))
+    }
+
+    /// Update the fields of a course work material which are set in `material`.
+    pub fn patch(
+        &self,
+        id: &str,
+        material: &CourseWorkMaterialModify,
+    ) -> Call<'a, CourseWorkMaterial> {
+        self.client
+            .request(
+                Method::PATCH,
+                &["courses", &self.course_id, "courseWorkMaterials", id],
+            )
+            .query(&[("updateMask", material.update_mask())])
+            .json(material)
+    }
+
+    /// Delete a course work material.
+    pub fn delete(&self, id: &str) -> Call<'a, Empty> {
+        self.client.request(
+            Method::DELETE,
+            &["courses", &self.course_id, "courseWorkMaterials", id],
+        )
+    }
+}
+
+impl List<'_, ListCourseWorkMaterialResponse> {
+    /// Only return course work materials in one of these states. If unset, only
+    /// [`CourseWorkMaterialState::Published`] materials are returned.
+    pub fn course_work_material_states(self, states: &[CourseWorkMaterialState]) -> Self {
+        let query: Vec<_> = states
+            .iter()
+            .map(|state| ("courseWorkMaterialStates", state))
+            .collect();
+        self.query(&query)
+    }
+
+    /// Sort results by `updateTime`, for example `updateTime asc`. Defaults to descending.
+    pub fn order_by(self, order_by: &str) -> Self {
+        self.query(&[("orderBy", order_by)])
+    }
+
+    /// Only return course work materials with at least one link material whose URL partially matches `link`.
+    pub fn material_link(self, link: &str) -> Self {
+        self.query(&[("materialLink", link)])
+    }
+
+    /// Only return course work materials with at least one Drive material whose ID matches `drive_id`.
+    pub fn material_drive_id(self, drive_id: &str) -> Self {
+        self.query(&[("materialDriveId", drive_id)])
+    }
+}
@@ -0,0 +1,141 @@
+use reqwest::Method;
+
+pub mod materials;
+pub mod rubrics;
+pub mod submissions;
+
+use super::{
+    add_ons::{AddOnAttachments, AddOnStudentSubmissions},
+    Courses,
+};
+use crate::{
+    client::{Call, Client, List},
+    model::{
+        courses::{
+            aliases::AliasScope,
+            course_work::{
+                CourseWork, CourseWorkCreate, CourseWorkModify, CourseWorkState,
+                ListCourseWorkResponse,
+            },
+        },
+        Empty, ModifyAssignees,
+    },
+};
+
+impl<'a> Courses<'a> {
+    /// Operations on the course work of a course.
+    #[must_use]
+    pub fn course_work(&self, course_id: impl Into<AliasScope>) -> CourseWorks<'a> {
+        CourseWorks {
+            client: self.client,
+            course_id: course_id.into().to_string(),
+        }
+    }
+}
+
+/// Operations on `courses.courseWork`, created by [`Courses::course_work`].
+#[derive(Debug, Clone)]
+pub struct CourseWorks<'a> {
+    client: &'a Client,
+    course_id: String,
+}
+
+impl<'a> CourseWorks<'a> {
+    /// Create course work.
+    ///
+    /// Only the Developer Console project that created course work may modify it later, so create it with the same
+    /// OAuth client that will be patching it.
+    pub fn create(&self, course_work: &CourseWorkCreate) -> Call<'a, CourseWork> {
+        self.client
+            .request(Method::POST, &["courses", &self.course_id, "courseWork"])
+            .json(course_work)
+    }
+
+    /// Get course work.
+    pub fn get(&self, id: &str) -> Call<'a, CourseWork> {
+        self.client
+            .request(Method::GET, &["courses", &self.course_id, "courseWork", id])
+    }
+
+    /// Operations on the add-on attachments of a course work.
+    #[must_use]
+    pub fn add_on_attachments(&self, item_id: impl Into<String>) -> AddOnAttachments<'a> {
+        AddOnAttachments::new(self.client, &self.course_id, "courseWork", item_id.into())
+    }
+
+    /// Operations on the student submissions for an add-on attachment of a course work. Only course work has
+    /// submissions for add-on attachments.
+    #[must_use]
+    pub fn add_on_student_submissions(
+        &self,
+        item_id: impl Into<String>,
+        attachment_id: impl Into<String>,
+    ) -> AddOnStudentSubmissions<'a> {
+        AddOnStudentSubmissions::new(
+            self.client,
+            &self.course_id,
+            item_id.into(),
+            attachment_id.into(),
+        )
+    }
+
+    /// List the course work the requesting user can view.
+    pub fn list(&self) -> List<'a, ListCourseWorkResponse> {
+        List::new(
+            self.client
+                .request(Method::GET, &["courses", &self.course_id, "courseWork"]),
+        )
+    }
+
+    /// Update the fields of course work which are set in `course_work`.
+    pub fn patch(&self, id: &str, course_work: &CourseWorkModify) -> Call<'a, CourseWork> {
+        self.client
+            .request(
+                Method::PATCH,
+                &["courses", &self.course_id, "courseWork", id],
+            )
+            .query(&[("updateMask", course_work.update_mask())])
+            .json(course_work)
+    }
+
+    /// Delete course work.
+    pub fn delete(&self, id: &str) -> Call<'a, Empty> {
+        self.client.request(
+            Method::DELETE,
+            &["courses", &self.course_id, "courseWork", id],
+        )
+    }
+
+    /// Change which students are assigned course work.
+    pub fn modify_assignees(&self, id: &str, assignees: &ModifyAssignees) -> Call<'a, CourseWork> {
+        self.client
+            .request(
+                Method::POST,
+                &[
+                    "courses",
+                    &self.course_id,
+                    "courseWork",
+                    &format!("{id}:modifyAssignees"),
+                ],
+            )
+            .json(assignees)
+    }
+}
+
+impl List<'_, ListCourseWorkResponse> {
+    /// Only return course work in one of these states. If unset, only [`CourseWorkState::Published`] course work is
+    /// returned.
+    pub fn course_work_states(self, course_work_states: &[CourseWorkState]) -> Self {
+        let query: Vec<_> = course_work_states
+            .iter()
+            .map(|state| ("courseWorkStates", state))
+            .collect();
+        self.query(&query)
+    }
+
+    /// Sort results by `updateTime` and/or `dueDate`, for example `dueDate asc,updateTime desc`. Defaults to
+    /// `updateTime desc`.
+    pub fn order_by(self, order_by: &str) -> Self {
+        self.query(&[("orderBy", order_by)])
+    }
+}
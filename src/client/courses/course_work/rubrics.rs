@@ -0,0 +1,95 @@
+use reqwest::Method;
+
+use super::CourseWorks;
+use crate::{
+    client::{Call, Client, List},
+    model::{
+        courses::course_work::rubrics::{ListRubricsResponse, Rubric, RubricCreate, RubricModify},
+        Empty,
+    },
+};
+
+impl<'a> CourseWorks<'a> {
+    /// Operations on the rubrics of a course work.
+    ///
+    /// To copy a rubric to other course work, create [`Rubric::to_create`] on each of them.
+    #[must_use]
+    pub fn rubrics(&self, course_work_id: impl Into<String>) -> Rubrics<'a> {
+        Rubrics {
+            client: self.client,
+            course_id: self.course_id.clone(),
+            course_work_id: course_work_id.into(),
+        }
+    }
+}
+
+/// Operations on `courses.courseWork.rubrics`, created by [`CourseWorks::rubrics`].
+#[derive(Debug, Clone)]
+pub struct Rubrics<'a> {
+    client: &'a Client,
+    course_id: String,
+    course_work_id: String,
+}
+
+impl<'a> Rubrics<'a> {
+    /// Create a rubric. Fails with `ALREADY_EXISTS` if the course work already has one.
+    pub fn create(&self, rubric: &RubricCreate) -> Call<'a, Rubric> {
+        self.client
+            .request(
+                Method::POST,
+                &[
+                    "courses",
+                    &self.course_id,
+                    "courseWork",
+                    &self.course_work_id,
+                    "rubrics",
+                ],
+            )
+            .json(rubric)
+    }
+
+    /// Get a rubric.
+    pub fn get(&self, id: &str) -> Call<'a, Rubric> {
+        self.call(Method::GET, id)
+    }
+
+    /// List the rubrics of the course work.
+    pub fn list(&self) -> List<'a, ListRubricsResponse> {
+        List::new(self.client.request(
+            Method::GET,
+            &[
+                "courses",
+                &self.course_id,
+                "courseWork",
+                &self.course_work_id,
+                "rubrics",
+            ],
+        ))
+    }
+
+    /// Update the fields of a rubric which are set in `rubric`.
+    pub fn patch(&self, id: &str, rubric: &RubricModify) -> Call<'a, Rubric> {
+        self.call(Method::PATCH, id)
+            .query(&[("updateMask", rubric.update_mask())])
+            .json(rubric)
+    }
+
+    /// Delete a rubric.
+    pub fn delete(&self, id: &str) -> Call<'a, Empty> {
+        self.call(Method::DELETE, id)
+    }
+
+    fn call<T>(&self, method: Method, id: &str) -> Call<'a, T> {
+        self.client.request(
+            method,
+            &[
+                "courses",
+                &self.course_id,
+                "courseWork",
+                &self.course_work_id,
+                "rubrics",
+                id,
+            ],
+        )
+    }
+}
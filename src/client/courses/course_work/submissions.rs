@@ -0,0 +1,127 @@
+use reqwest::Method;
+
+use super::CourseWorks;
+use crate::{
+    client::{Call, Client, List},
+    model::{
+        courses::course_work::submissions::{
+            LateValues, ListStudentSubmissionsResponse, ModifyAttachments, StudentSubmission,
+            StudentSubmissionModify, SubmissionState,
+        },
+        user_profiles::UserId,
+        Empty,
+    },
+};
+
+impl<'a> CourseWorks<'a> {
+    /// Operations on the student submissions for course work. Pass `-` as `course_work_id` to list submissions for
+    /// all course work in the course.
+    #[must_use]
+    pub fn student_submissions(&self, course_work_id: impl Into<String>) -> StudentSubmissions<'a> {
+        StudentSubmissions {
+            client: self.client,
+            course_id: self.course_id.clone(),
+            course_work_id: course_work_id.into(),
+        }
+    }
+}
+
+/// Operations on `courses.courseWork.studentSubmissions`, created by [`CourseWorks::student_submissions`].
+#[derive(Debug, Clone)]
+pub struct StudentSubmissions<'a> {
+    client: &'a Client,
+    course_id: String,
+    course_work_id: String,
+}
+
+impl<'a> StudentSubmissions<'a> {
+    /// Get a student submission.
+    pub fn get(&self, id: &str) -> Call<'a, StudentSubmission> {
+        self.call(Method::GET, id)
+    }
+
+    /// List the student submissions the requesting user can view.
+    pub fn list(&self) -> List<'a, ListStudentSubmissionsResponse> {
+        List::new(self.client.request(
+            Method::GET,
+            &[
+                "courses",
+                &self.course_id,
+                "courseWork",
+                &self.course_work_id,
+                "studentSubmissions",
+            ],
+        ))
+    }
+
+    /// Update the grades of a student submission which are set in `submission`.
+    pub fn patch(
+        &self,
+        id: &str,
+        submission: &StudentSubmissionModify,
+    ) -> Call<'a, StudentSubmission> {
+        self.call(Method::PATCH, id)
+            .query(&[("updateMask", submission.update_mask())])
+            .json(submission)
+    }
+
+    /// Turn in a student submission. Only the student that owns the submission may turn it in.
+    pub fn turn_in(&self, id: &str) -> Call<'a, Empty> {
+        self.call(Method::POST, &format!("{id}:turnIn"))
+            .json(&Empty {})
+    }
+
+    /// Reclaim a turned in student submission, so the student can modify it again.
+    pub fn reclaim(&self, id: &str) -> Call<'a, Empty> {
+        self.call(Method::POST, &format!("{id}:reclaim"))
+            .json(&Empty {})
+    }
+
+    /// Return a student submission, which also returns its assigned grade to the student.
+    pub fn return_submission(&self, id: &str) -> Call<'a, Empty> {
+        self.call(Method::POST, &format!("{id}:return"))
+            .json(&Empty {})
+    }
+
+    /// Add attachments to a student submission.
+    pub fn modify_attachments(
+        &self,
+        id: &str,
+        attachments: &ModifyAttachments,
+    ) -> Call<'a, StudentSubmission> {
+        self.call(Method::POST, &format!("{id}:modifyAttachments"))
+            .json(attachments)
+    }
+
+    fn call<T>(&self, method: Method, last: &str) -> Call<'a, T> {
+        self.client.request(
+            method,
+            &[
+                "courses",
+                &self.course_id,
+                "courseWork",
+                &self.course_work_id,
+                "studentSubmissions",
+                last,
+            ],
+        )
+    }
+}
+
+impl List<'_, ListStudentSubmissionsResponse> {
+    /// Only return submissions owned by this student.
+    pub fn user_id(self, user_id: &UserId) -> Self {
+        self.query(&[("userId", user_id)])
+    }
+
+    /// Only return submissions in one of these states.
+    pub fn states(self, states: &[SubmissionState]) -> Self {
+        let query: Vec<_> = states.iter().map(|state| ("states", state)).collect();
+        self.query(&query)
+    }
+
+    /// Only return submissions that are, or are not, late.
+    pub fn late(self, late: LateValues) -> Self {
+        self.query(&[("late", late)])
+    }
+}
@@ -0,0 +1,122 @@
+use reqwest::Method;
+
+pub mod add_ons;
+pub mod aliases;
+pub mod announcements;
+pub mod course_work;
+pub mod student_groups;
+pub mod students;
+pub mod teachers;
+pub mod topics;
+
+use super::{Call, Client, List};
+use crate::model::{
+    courses::{
+        aliases::AliasScope,
+        grading_periods::{GradingPeriodSettings, GradingPeriodSettingsModify},
+        Course, CourseCreate, CourseModify, CourseState, ListCoursesResponse,
+    },
+    user_profiles::UserId,
+    Empty,
+};
+
+impl Client {
+    /// Operations on `courses`.
+    #[must_use]
+    pub const fn courses(&self) -> Courses<'_> {
+        Courses { client: self }
+    }
+}
+
+/// Operations on `courses`, created by [`Client::courses`].
+#[derive(Debug, Clone, Copy)]
+pub struct Courses<'a> {
+    client: &'a Client,
+}
+
+impl<'a> Courses<'a> {
+    /// Create a course owned by [`CourseCreate::owner_id`].
+    pub fn create(&self, course: &CourseCreate) -> Call<'a, Course> {
+        self.client.request(Method::POST, &["courses"]).json(course)
+    }
+
+    /// Get a course by its identifier or alias.
+    pub fn get(&self, id: impl Into<AliasScope>) -> Call<'a, Course> {
+        self.client
+            .request(Method::GET, &["courses", &id.into().to_string()])
+    }
+
+    /// List the courses the requesting user can view.
+    pub fn list(&self) -> List<'a, ListCoursesResponse> {
+        List::new(self.client.request(Method::GET, &["courses"]))
+    }
+
+    /// Update the fields of a course which are set in `course`.
+    pub fn patch(&self, id: impl Into<AliasScope>, course: &CourseModify) -> Call<'a, Course> {
+        self.client
+            .request(Method::PATCH, &["courses", &id.into().to_string()])
+            .query(&[("updateMask", course.update_mask())])
+            .json(course)
+    }
+
+    /// Replace a course.
+    pub fn update(&self, id: impl Into<AliasScope>, course: &Course) -> Call<'a, Course> {
+        self.client
+            .request(Method::PUT, &["courses", &id.into().to_string()])
+            .json(course)
+    }
+
+    /// Delete a course.
+    pub fn delete(&self, id: impl Into<AliasScope>) -> Call<'a, Empty> {
+        self.client
+            .request(Method::DELETE, &["courses", &id.into().to_string()])
+    }
+
+    /// Get the grading period settings of a course.
+    pub fn get_grading_period_settings(
+        &self,
+        id: impl Into<AliasScope>,
+    ) -> Call<'a, GradingPeriodSettings> {
+        self.client.request(
+            Method::GET,
+            &["courses", &id.into().to_string(), "gradingPeriodSettings"],
+        )
+    }
+
+    /// Update the grading period settings of a course which are set in `settings`.
+    pub fn update_grading_period_settings(
+        &self,
+        id: impl Into<AliasScope>,
+        settings: &GradingPeriodSettingsModify,
+    ) -> Call<'a, GradingPeriodSettings> {
+        self.client
+            .request(
+                Method::PATCH,
+                &["courses", &id.into().to_string(), "gradingPeriodSettings"],
+            )
+            .query(&[("updateMask", settings.update_mask())])
+            .json(settings)
+    }
+}
+
+impl List<'_, ListCoursesResponse> {
+    /// Only return courses that have this student.
+    pub fn student_id(self, student_id: &UserId) -> Self {
+        self.query(&[("studentId", student_id)])
+    }
+
+    /// Only return courses that have this teacher.
+    pub fn teacher_id(self, teacher_id: &UserId) -> Self {
+        self.query(&[("teacherId", teacher_id)])
+    }
+
+    /// Only return courses in one of these states. If unset, courses in every state except
+    /// [`CourseState::Declined`] are returned.
+    pub fn course_states(self, course_states: &[CourseState]) -> Self {
+        let query: Vec<_> = course_states
+            .iter()
+            .map(|state| ("courseStates", state))
+            .collect();
+        self.query(&query)
+    }
+}
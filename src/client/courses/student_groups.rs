@@ -0,0 +1,137 @@
+use reqwest::Method;
+
+use super::Courses;
+use crate::{
+    client::{Call, Client, List},
+    model::{
+        courses::{
+            aliases::AliasScope,
+            student_groups::{
+                ListStudentGroupMembersResponse, ListStudentGroupsResponse, StudentGroup,
+                StudentGroupCreate, StudentGroupMember, StudentGroupMemberCreate,
+                StudentGroupModify,
+            },
+        },
+        user_profiles::UserId,
+        Empty,
+    },
+};
+
+impl<'a> Courses<'a> {
+    /// Operations on the student groups of a course.
+    #[must_use]
+    pub fn student_groups(&self, course_id: impl Into<AliasScope>) -> StudentGroups<'a> {
+        StudentGroups {
+            client: self.client,
+            course_id: course_id.into().to_string(),
+        }
+    }
+}
+
+/// Operations on `courses.studentGroups`, created by [`Courses::student_groups`].
+#[derive(Debug, Clone)]
+pub struct StudentGroups<'a> {
+    client: &'a Client,
+    course_id: String,
+}
+
+impl<'a> StudentGroups<'a> {
+    /// Create a student group.
+    pub fn create(&self, group: &StudentGroupCreate) -> Call<'a, StudentGroup> {
+        self.client
+            .request(Method::POST, &["courses", &self.course_id, "studentGroups"])
+            .json(group)
+    }
+
+    /// List the student groups of the course.
+    pub fn list(&self) -> List<'a, ListStudentGroupsResponse> {
+        List::new(
+            self.client
+                .request(Method::GET, &["courses", &self.course_id, "studentGroups"]),
+        )
+    }
+
+    /// Update the fields of a student group which are set in `group`.
+    pub fn patch(&self, id: &str, group: &StudentGroupModify) -> Call<'a, StudentGroup> {
+        self.client
+            .request(
+                Method::PATCH,
+                &["courses", &self.course_id, "studentGroups", id],
+            )
+            .query(&[("updateMask", group.update_mask())])
+            .json(group)
+    }
+
+    /// Delete a student group.
+    pub fn delete(&self, id: &str) -> Call<'a, Empty> {
+        self.client.request(
+            Method::DELETE,
+            &["courses", &self.course_id, "studentGroups", id],
+        )
+    }
+
+    /// Operations on the members of a student group.
+    #[must_use]
+    pub fn members(&self, student_group_id: impl Into<String>) -> StudentGroupMembers<'a> {
+        StudentGroupMembers {
+            client: self.client,
+            course_id: self.course_id.clone(),
+            student_group_id: student_group_id.into(),
+        }
+    }
+}
+
+/// Operations on `courses.studentGroups.studentGroupMembers`, created by [`StudentGroups::members`].
+#[derive(Debug, Clone)]
+pub struct StudentGroupMembers<'a> {
+    client: &'a Client,
+    course_id: String,
+    student_group_id: String,
+}
+
+impl<'a> StudentGroupMembers<'a> {
+    /// Add a student of the course to the group.
+    pub fn add(&self, user_id: UserId) -> Call<'a, StudentGroupMember> {
+        self.client
+            .request(
+                Method::POST,
+                &[
+                    "courses",
+                    &self.course_id,
+                    "studentGroups",
+                    &self.student_group_id,
+                    "studentGroupMembers",
+                ],
+            )
+            .json(&StudentGroupMemberCreate { user_id })
+    }
+
+    /// List the members of the group.
+    pub fn list(&self) -> List<'a, ListStudentGroupMembersResponse> {
+        List::new(self.client.request(
+            Method::GET,
+            &[
+                "courses",
+                &self.course_id,
+                "studentGroups",
+                &self.student_group_id,
+                "studentGroupMembers",
+            ],
+        ))
+    }
+
+    /// Remove a student from the group. They remain a student in the course.
+    pub fn remove(&self, user_id: &UserId) -> Call<'a, Empty> {
+        self.client.request(
+            Method::DELETE,
+            &[
+                "courses",
+                &self.course_id,
+                "studentGroups",
+                &self.student_group_id,
+                "studentGroupMembers",
+                user_id.as_str(),
+            ],
+        )
+    }
+}
@@ -0,0 +1,71 @@
+use reqwest::Method;
+
+use super::Courses;
+use crate::{
+    client::{Call, Client, List},
+    model::{
+        courses::{
+            aliases::AliasScope,
+            students::{ListStudentsResponse, Student, StudentCreate},
+        },
+        user_profiles::UserId,
+        Empty,
+    },
+};
+
+impl<'a> Courses<'a> {
+    /// Operations on the students of a course.
+    #[must_use]
+    pub fn students(&self, course_id: impl Into<AliasScope>) -> Students<'a> {
+        Students {
+            client: self.client,
+            course_id: course_id.into().to_string(),
+        }
+    }
+}
+
+/// Operations on `courses.students`, created by [`Courses::students`].
+#[derive(Debug, Clone)]
+pub struct Students<'a> {
+    client: &'a Client,
+    course_id: String,
+}
+
+impl<'a> Students<'a> {
+    /// Add a user as a student of the course. Only domain administrators may add other users directly.
+    pub fn create(&self, user_id: UserId) -> Call<'a, Student> {
+        self.client
+            .request(Method::POST, &["courses", &self.course_id, "students"])
+            .json(&StudentCreate { user_id })
+    }
+
+    /// Join the course as the requesting user, using the course's enrollment code.
+    pub fn join(&self, enrollment_code: &str) -> Call<'a, Student> {
+        self.create(UserId::Me)
+            .query(&[("enrollmentCode", enrollment_code)])
+    }
+
+    /// Get a student of the course.
+    pub fn get(&self, user_id: &UserId) -> Call<'a, Student> {
+        self.client.request(
+            Method::GET,
+            &["courses", &self.course_id, "students", user_id.as_str()],
+        )
+    }
+
+    /// List the students of the course.
+    pub fn list(&self) -> List<'a, ListStudentsResponse> {
+        List::new(
+            self.client
+                .request(Method::GET, &["courses", &self.course_id, "students"]),
+        )
+    }
+
+    /// Remove a student from the course.
+    pub fn delete(&self, user_id: &UserId) -> Call<'a, Empty> {
+        self.client.request(
+            Method::DELETE,
+            &["courses", &self.course_id, "students", user_id.as_str()],
+        )
+    }
+}
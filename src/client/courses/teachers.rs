@@ -0,0 +1,66 @@
+use reqwest::Method;
+
+use super::Courses;
+use crate::{
+    client::{Call, Client, List},
+    model::{
+        courses::{
+            aliases::AliasScope,
+            teachers::{ListTeachersResponse, Teacher, TeacherCreate},
+        },
+        user_profiles::UserId,
+        Empty,
+    },
+};
+
+impl<'a> Courses<'a> {
+    /// Operations on the teachers of a course.
+    #[must_use]
+    pub fn teachers(&self, course_id: impl Into<AliasScope>) -> Teachers<'a> {
+        Teachers {
+            client: self.client,
+            course_id: course_id.into().to_string(),
+        }
+    }
+}
+
+/// Operations on `courses.teachers`, created by [`Courses::teachers`].
+#[derive(Debug, Clone)]
+pub struct Teachers<'a> {
+    client: &'a Client,
+    course_id: String,
+}
+
+impl<'a> Teachers<'a> {
+    /// Add a user as a teacher of the course. Only domain administrators and course owners may add teachers
+    /// directly; other users should be invited instead.
+    pub fn create(&self, user_id: UserId) -> Call<'a, Teacher> {
+        self.client
+            .request(Method::POST, &["courses", &self.course_id, "teachers"])
+            .json(&TeacherCreate { user_id })
+    }
+
+    /// Get a teacher of the course.
+    pub fn get(&self, user_id: &UserId) -> Call<'a, Teacher> {
+        self.client.request(
+            Method::GET,
+            &["courses", &self.course_id, "teachers", user_id.as_str()],
+        )
+    }
+
+    /// List the teachers of the course.
+    pub fn list(&self) -> List<'a, ListTeachersResponse> {
+        List::new(
+            self.client
+                .request(Method::GET, &["courses", &self.course_id, "teachers"]),
+        )
+    }
+
+    /// Remove a teacher from the course.
+    pub fn delete(&self, user_id: &UserId) -> Call<'a, Empty> {
+        self.client.request(
+            Method::DELETE,
+            &["courses", &self.course_id, "teachers", user_id.as_str()],
+        )
+    }
+}
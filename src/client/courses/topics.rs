@@ -0,0 +1,75 @@
+use reqwest::Method;
+
+use super::Courses;
+use crate::{
+    client::{Call, Client, List},
+    model::{
+        courses::{
+            aliases::AliasScope,
+            topics::{ListTopicResponse, Topic, TopicCreate, TopicId, TopicModify},
+        },
+        Empty,
+    },
+};
+
+impl<'a> Courses<'a> {
+    /// Operations on the topics of a course.
+    #[must_use]
+    pub fn topics(&self, course_id: impl Into<AliasScope>) -> Topics<'a> {
+        Topics {
+            client: self.client,
+            course_id: course_id.into().to_string(),
+        }
+    }
+}
+
+/// Operations on `courses.topics`, created by [`Courses::topics`].
+#[derive(Debug, Clone)]
+pub struct Topics<'a> {
+    client: &'a Client,
+    course_id: String,
+}
+
+impl<'a> Topics<'a> {
+    /// Create a topic.
+    pub fn create(&self, topic: &TopicCreate) -> Call<'a, Topic> {
+        self.client
+            .request(Method::POST, &["courses", &self.course_id, "topics"])
+            .json(topic)
+    }
+
+    /// Get a topic.
+    pub fn get(&self, id: &TopicId) -> Call<'a, Topic> {
+        self.client.request(
+            Method::GET,
+            &["courses", &self.course_id, "topics", id.as_str()],
+        )
+    }
+
+    /// List the topics of the course.
+    pub fn list(&self) -> List<'a, ListTopicResponse> {
+        List::new(
+            self.client
+                .request(Method::GET, &["courses", &self.course_id, "topics"]),
+        )
+    }
+
+    /// Update the fields of a topic which are set in `topic`.
+    pub fn patch(&self, id: &TopicId, topic: &TopicModify) -> Call<'a, Topic> {
+        self.client
+            .request(
+                Method::PATCH,
+                &["courses", &self.course_id, "topics", id.as_str()],
+            )
+            .query(&[("updateMask", topic.update_mask())])
+            .json(topic)
+    }
+
+    /// Delete a topic.
+    pub fn delete(&self, id: &TopicId) -> Call<'a, Empty> {
+        self.client.request(
+            Method::DELETE,
+            &["courses", &self.course_id, "topics", id.as_str()],
+        )
+    }
+}
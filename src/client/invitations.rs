@@ -0,0 +1,69 @@
+use reqwest::Method;
+
+use super::{Call, Client, List};
+use crate::model::{
+    courses::aliases::AliasScope,
+    invitations::{Invitation, InvitationCreate, ListInvitationsResponse},
+    user_profiles::UserId,
+    Empty,
+};
+
+impl Client {
+    /// Operations on `invitations`.
+    #[must_use]
+    pub const fn invitations(&self) -> Invitations<'_> {
+        Invitations { client: self }
+    }
+}
+
+/// Operations on `invitations`, created by [`Client::invitations`].
+#[derive(Debug, Clone, Copy)]
+pub struct Invitations<'a> {
+    client: &'a Client,
+}
+
+impl<'a> Invitations<'a> {
+    /// Invite a user to a course. Fails with `ALREADY_EXISTS` if the user is already invited or a member.
+    pub fn create(&self, invitation: &InvitationCreate) -> Call<'a, Invitation> {
+        self.client
+            .request(Method::POST, &["invitations"])
+            .json(invitation)
+    }
+
+    /// Get an invitation.
+    ///
+    /// Invitations are deleted once accepted, so this fails with `NOT_FOUND` for an accepted invitation, see
+    /// [`Error::is_not_found`](crate::Error::is_not_found).
+    pub fn get(&self, id: &str) -> Call<'a, Invitation> {
+        self.client.request(Method::GET, &["invitations", id])
+    }
+
+    /// List invitations. At least one of [`List::user_id`] or [`List::course_id`] must be set.
+    pub fn list(&self) -> List<'a, ListInvitationsResponse> {
+        List::new(self.client.request(Method::GET, &["invitations"]))
+    }
+
+    /// Delete an invitation.
+    pub fn delete(&self, id: &str) -> Call<'a, Empty> {
+        self.client.request(Method::DELETE, &["invitations", id])
+    }
+
+    /// Accept an invitation on behalf of the invited user, adding them to the course.
+    pub fn accept(&self, id: &str) -> Call<'a, Empty> {
+        self.client
+            .request(Method::POST, &["invitations", &format!("{id}:accept")])
+            .json(&Empty {})
+    }
+}
+
+impl List<'_, ListInvitationsResponse> {
+    /// Only return invitations for this user.
+    pub fn user_id(self, user_id: &UserId) -> Self {
+        self.query(&[("userId", user_id)])
+    }
+
+    /// Only return invitations to this course.
+    pub fn course_id(self, course_id: impl Into<AliasScope>) -> Self {
+        self.query(&[("courseId", course_id.into())])
+    }
+}
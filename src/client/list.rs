@@ -0,0 +1,231 @@
+use futures::{stream, Stream};
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::Call;
+use crate::{model::Page, Result};
+
+/// A pending call to a list method, which can fetch a single page or follow `nextPageToken` across all of them.
+#[must_use = "lists do nothing unless sent or streamed"]
+pub struct List<'a, P> {
+    call: Call<'a, P>,
+    page_size: Option<u32>,
+    page_token: Option<String>,
+    limit: Option<usize>,
+}
+
+impl<'a, P> List<'a, P> {
+    pub(crate) const fn new(call: Call<'a, P>) -> Self {
+        Self {
+            call,
+            page_size: None,
+            page_token: None,
+            limit: None,
+        }
+    }
+
+    /// Ask for at most `page_size` items per page. The server may return fewer, and picks its own default if unset.
+    pub const fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Start from the page identified by `page_token`, as returned by a previous list call.
+    pub fn page_token(mut self, page_token: impl Into<String>) -> Self {
+        self.page_token = Some(page_token.into());
+        self
+    }
+
+    /// Stop [`List::stream`] and [`List::all`] after `limit` items in total.
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Append query parameters, such as filters, to every page request.
+    pub fn query<Q: Serialize + ?Sized>(mut self, query: &Q) -> Self {
+        self.call = self.call.query(query);
+        self
+    }
+}
+
+impl<'a, P: Page + DeserializeOwned + 'a> List<'a, P> {
+    /// Fetch a single page.
+    ///
+    /// # Errors
+    /// Errors if the request fails, see [`Call::send`].
+    pub async fn send(self) -> Result<P> {
+        Self::page(self.call, self.page_size, self.page_token.as_deref())
+            .send()
+            .await
+    }
+
+    /// Stream every item, fetching further pages as the stream is polled.
+    ///
+    /// The stream ends after the last page, after [`List::limit`] items, or after yielding the first error.
+    pub fn stream(self) -> impl Stream<Item = Result<P::Item>> + 'a {
+        let state = State {
+            cursor: self.page_token.map_or(Cursor::Start, Cursor::Next),
+            items: Vec::new().into_iter(),
+            remaining: self.limit,
+            call: self.call,
+            page_size: self.page_size,
+        };
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if state.remaining == Some(0) {
+                    return None;
+                }
+                if let Some(item) = state.items.next() {
+                    state.remaining = state.remaining.map(|remaining| remaining - 1);
+                    return Some((Ok(item), state));
+                }
+                let page_token = match &state.cursor {
+                    Cursor::Start => None,
+                    Cursor::Next(page_token) => Some(page_token.as_str()),
+                    Cursor::Done => return None,
+                };
+                let call = Self::page(state.call.try_clone()?, state.page_size, page_token);
+                match call.send().await {
+                    Ok(page) => {
+                        state.cursor = match page.next_page_token() {
+                            Some(next) if !next.is_empty() => Cursor::Next(next.to_owned()),
+                            _ => Cursor::Done,
+                        };
+                        state.items = page.into_items().into_iter();
+                    }
+                    Err(error) => {
+                        state.cursor = Cursor::Done;
+                        return Some((Err(error), state));
+                    }
+                }
+            }
+        })
+    }
+
+    /// Fetch every item into a [`Vec`], respecting [`List::limit`].
+    ///
+    /// # Errors
+    /// Errors if any page request fails, see [`Call::send`].
+    pub async fn all(self) -> Result<Vec<P::Item>> {
+        use futures::TryStreamExt;
+
+        self.stream().try_collect().await
+    }
+
+    fn page(call: Call<'a, P>, page_size: Option<u32>, page_token: Option<&str>) -> Call<'a, P> {
+        let mut call = call;
+        if let Some(page_size) = page_size {
+            call = call.query(&[("pageSize", page_size)]);
+        }
+        if let Some(page_token) = page_token {
+            call = call.query(&[("pageToken", page_token)]);
+        }
+        call
+    }
+}
+
+enum Cursor {
+    Start,
+    Next(String),
+    Done,
+}
+
+struct State<'a, P: Page> {
+    call: Call<'a, P>,
+    page_size: Option<u32>,
+    cursor: Cursor,
+    items: std::vec::IntoIter<P::Item>,
+    remaining: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::Method;
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::{
+        auth::StaticToken,
+        client::Client,
+        test_server::{Request, TestServer},
+    };
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Numbers {
+        items: Vec<u32>,
+        next_page_token: Option<String>,
+    }
+
+    impl Page for Numbers {
+        type Item = u32;
+
+        fn next_page_token(&self) -> Option<&str> {
+            self.next_page_token.as_deref()
+        }
+
+        fn into_items(self) -> Vec<u32> {
+            self.items
+        }
+    }
+
+    /// Serve three pages of two numbers, chained by `pageToken=2` and `pageToken=3`, with `last_token` on the last.
+    fn serve(last_token: &'static str) -> (TestServer, Client) {
+        let server = TestServer::start(move |request: &Request| {
+            let body = if request.target.contains("pageToken=2") {
+                r#"{"items": [3, 4], "nextPageToken": "3"}"#.to_string()
+            } else if request.target.contains("pageToken=3") {
+                format!(r#"{{"items": [5, 6]{last_token}}}"#)
+            } else {
+                r#"{"items": [1, 2], "nextPageToken": "2"}"#.to_string()
+            };
+            (200, body)
+        });
+        let client = Client::builder(StaticToken::new("token"))
+            .endpoint(server.url().parse().unwrap())
+            .build();
+        (server, client)
+    }
+
+    fn list(client: &Client) -> List<'_, Numbers> {
+        List::new(client.request(Method::GET, &["numbers"]))
+    }
+
+    #[tokio::test]
+    async fn follows_next_page_token() {
+        let (server, client) = serve("");
+        let items = list(&client).page_size(2).all().await.unwrap();
+        assert_eq!(items, [1, 2, 3, 4, 5, 6]);
+        let requests = server.requests();
+        for request in &requests {
+            assert_eq!(request.method, "GET");
+            assert_eq!(request.header("authorization"), Some("Bearer token"));
+            assert!(request.body.is_empty());
+        }
+        let targets: Vec<_> = requests.into_iter().map(|request| request.target).collect();
+        assert_eq!(
+            targets,
+            [
+                "/v1/numbers?pageSize=2",
+                "/v1/numbers?pageSize=2&pageToken=2",
+                "/v1/numbers?pageSize=2&pageToken=3",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn stops_at_limit() {
+        let (server, client) = serve("");
+        let items = list(&client).limit(3).all().await.unwrap();
+        assert_eq!(items, [1, 2, 3]);
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn treats_empty_token_as_end() {
+        let (server, client) = serve(r#", "nextPageToken": """#);
+        let items = list(&client).page_token("2").all().await.unwrap();
+        assert_eq!(items, [3, 4, 5, 6]);
+        assert_eq!(server.requests().len(), 2);
+    }
+}
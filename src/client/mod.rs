@@ -0,0 +1,184 @@
+//! Asynchronous HTTP client for the Classroom API.
+//!
+//! [`Client`] attaches an access token to every request, resolves paths against the service endpoint and deserializes
+//! responses into the types in [`crate::model`].
+use std::sync::Arc;
+
+use reqwest::{Method, Url};
+
+use self::rate_limit::RateLimiter;
+use crate::{auth::TokenProvider, Result, API_VERSION, SERVICE_ENDPOINT};
+
+mod batch;
+mod call;
+pub mod courses;
+pub mod invitations;
+mod list;
+mod rate_limit;
+pub mod registrations;
+mod retry;
+pub mod user_profiles;
+
+pub use batch::Batch;
+pub use call::Call;
+pub use list::List;
+pub use rate_limit::{Quota, RateLimit};
+pub use retry::RetryPolicy;
+
+/// Client for the Classroom API.
+///
+/// Cloning a client is cheap, and clones share the underlying connection pool.
+#[derive(Clone)]
+pub struct Client {
+    http: reqwest::Client,
+    base_url: Url,
+    tokens: Arc<dyn TokenProvider>,
+    retry: Arc<RetryPolicy>,
+    limiter: Option<Arc<RateLimiter>>,
+}
+
+impl Client {
+    /// Create a client for the public Classroom endpoint.
+    #[must_use]
+    pub fn new(tokens: impl TokenProvider + 'static) -> Self {
+        Self::builder(tokens).build()
+    }
+
+    /// Start configuring a client.
+    #[must_use]
+    pub fn builder(tokens: impl TokenProvider + 'static) -> ClientBuilder {
+        ClientBuilder::new(Arc::new(tokens))
+    }
+
+    /// A client which authenticates with `tokens`, but otherwise shares this client's connection pool, endpoint,
+    /// retry policy and rate limit budgets.
+    ///
+    /// This is how to act as many users with one [`RateLimit`], by passing a
+    /// [`ServiceAccount`](crate::auth::ServiceAccount) impersonating each of them.
+    #[must_use]
+    pub fn with_tokens(&self, tokens: impl TokenProvider + 'static) -> Self {
+        Self {
+            tokens: Arc::new(tokens),
+            ..self.clone()
+        }
+    }
+
+    /// The versioned URL all request paths are resolved against, for example `https://classroom.googleapis.com/v1`.
+    #[must_use]
+    pub const fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    /// How failed calls are retried.
+    #[must_use]
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry
+    }
+
+    /// Build a call to an arbitrary API method.
+    ///
+    /// Each entry in `path` is a single, percent-encoded path segment appended to [`Client::base_url`], so identifiers
+    /// such as email addresses or aliases never need escaping by the caller.
+    #[allow(clippy::missing_panics_doc)] // checked in `ClientBuilder::build`
+    pub fn request<T>(&self, method: Method, path: &[&str]) -> Call<'_, T> {
+        let mut url = self.base_url.clone();
+        url.path_segments_mut()
+            .expect("base URL is always a hierarchical URL")
+            .extend(path);
+        Call::new(self, self.http.request(method, url))
+    }
+
+    pub(crate) async fn access_token(&self) -> Result<String> {
+        self.tokens.access_token(&self.http).await
+    }
+
+    /// Wait until a call with `method` fits in the rate limit budgets of the current user, if rate limited.
+    pub(crate) async fn throttle(&self, method: &Method) {
+        if let Some(limiter) = &self.limiter {
+            limiter.acquire(self.tokens.impersonated(), method).await;
+        }
+    }
+}
+
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("base_url", &self.base_url)
+            .field("retry", &self.retry)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Builder for a [`Client`].
+#[allow(clippy::module_name_repetitions)]
+pub struct ClientBuilder {
+    http: Option<reqwest::Client>,
+    endpoint: Url,
+    tokens: Arc<dyn TokenProvider>,
+    retry: RetryPolicy,
+    rate_limit: Option<RateLimit>,
+}
+
+impl ClientBuilder {
+    fn new(tokens: Arc<dyn TokenProvider>) -> Self {
+        Self {
+            http: None,
+            endpoint: Url::parse(SERVICE_ENDPOINT).expect("SERVICE_ENDPOINT is a valid URL"),
+            tokens,
+            retry: RetryPolicy::default(),
+            rate_limit: None,
+        }
+    }
+
+    /// Use an existing [`reqwest::Client`] instead of creating a new one.
+    #[must_use]
+    pub fn http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = Some(http);
+        self
+    }
+
+    /// Send requests to `endpoint` instead of [`SERVICE_ENDPOINT`]. The API version is appended to it.
+    #[must_use]
+    pub fn endpoint(mut self, endpoint: Url) -> Self {
+        self.endpoint = endpoint;
+        self
+    }
+
+    /// Retry failed calls according to `retry` instead of [`RetryPolicy::default`]. Use [`RetryPolicy::none`] to
+    /// disable retries.
+    #[must_use]
+    pub fn retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Limit how fast calls are sent. Calls are not limited by default.
+    #[must_use]
+    pub const fn rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Finish building the client.
+    ///
+    /// # Panics
+    /// Panics if the endpoint cannot be a base URL, such as a `data:` URL.
+    #[must_use]
+    pub fn build(self) -> Client {
+        let mut base_url = self.endpoint;
+        base_url
+            .path_segments_mut()
+            .expect("endpoint must be a hierarchical URL")
+            .pop_if_empty()
+            .push(&format!("v{API_VERSION}"));
+        Client {
+            http: self.http.unwrap_or_default(),
+            base_url,
+            tokens: self.tokens,
+            retry: Arc::new(self.retry),
+            limiter: self
+                .rate_limit
+                .map(|limit| Arc::new(RateLimiter::new(limit))),
+        }
+    }
+}
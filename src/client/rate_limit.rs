@@ -0,0 +1,280 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use reqwest::Method;
+
+/// Client-side limits on how fast calls are sent, to stay under Classroom's quotas.
+///
+/// Reads (`GET` calls) and writes (every other call) draw from separate budgets. Each user impersonated through
+/// domain-wide delegation, as reported by [`TokenProvider::impersonated`](crate::auth::TokenProvider::impersonated),
+/// has budgets of their own, so clients created with [`Client::with_tokens`](super::Client::with_tokens) can run jobs
+/// for many users without one of them starving the rest. With [`RateLimit::project`], every call also draws from
+/// read and write budgets shared by all users.
+///
+/// Calls which would exceed a budget wait until it has refilled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct RateLimit {
+    reads: Quota,
+    writes: Quota,
+    project: Option<(Quota, Quota)>,
+}
+
+impl RateLimit {
+    /// Limit reads to `reads` and writes to `writes`, for each user.
+    #[must_use]
+    pub const fn new(reads: Quota, writes: Quota) -> Self {
+        Self {
+            reads,
+            writes,
+            project: None,
+        }
+    }
+
+    /// Also limit reads to `reads` and writes to `writes` across all users, such as to the quota of the Cloud project.
+    #[must_use]
+    pub const fn project(mut self, reads: Quota, writes: Quota) -> Self {
+        self.project = Some((reads, writes));
+        self
+    }
+}
+
+/// A budget of requests which refills evenly over a period. Up to the whole budget may be spent in a burst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quota {
+    requests: u32,
+    period: Duration,
+}
+
+impl Quota {
+    /// Allow `requests` every `period`.
+    ///
+    /// # Panics
+    /// Panics if `requests` or `period` is zero.
+    #[must_use]
+    pub const fn new(requests: u32, period: Duration) -> Self {
+        assert!(requests > 0, "a quota must allow at least one request");
+        assert!(!period.is_zero(), "a quota period must not be zero");
+        Self { requests, period }
+    }
+
+    /// Allow `requests` every second.
+    ///
+    /// # Panics
+    /// Panics if `requests` is zero.
+    #[must_use]
+    pub const fn per_second(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(1))
+    }
+
+    /// Allow `requests` every minute.
+    ///
+    /// # Panics
+    /// Panics if `requests` is zero.
+    #[must_use]
+    pub const fn per_minute(requests: u32) -> Self {
+        Self::new(requests, Duration::from_mins(1))
+    }
+}
+
+/// The token buckets for a [`RateLimit`], shared by every client created from the same builder.
+#[derive(Debug)]
+pub struct RateLimiter {
+    limit: RateLimit,
+    buckets: Mutex<Buckets>,
+}
+
+/// Whose budget a bucket holds.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Owner {
+    Project,
+    User(Option<String>),
+}
+
+#[derive(Debug)]
+struct Buckets {
+    /// Buckets by owner and whether they are for writes. Missing buckets are full.
+    buckets: HashMap<(Owner, bool), Bucket>,
+    /// When idle buckets were last removed.
+    swept: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            buckets: Mutex::new(Buckets {
+                buckets: HashMap::new(),
+                swept: Instant::now(),
+            }),
+        }
+    }
+
+    /// Wait until `user` may send a call with `method`, and spend its budget.
+    pub async fn acquire(&self, user: Option<&str>, method: &Method) {
+        let write = *method != Method::GET;
+        while let Some(wait) = self.try_acquire(user, write, Instant::now()) {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Spend a call from every budget `user` draws from, or return how long until they all have one.
+    fn try_acquire(&self, user: Option<&str>, write: bool, now: Instant) -> Option<Duration> {
+        let pick = |(reads, writes)| if write { writes } else { reads };
+        let mut owners = vec![(
+            Owner::User(user.map(str::to_string)),
+            pick((self.limit.reads, self.limit.writes)),
+        )];
+        if let Some(project) = self.limit.project {
+            owners.push((Owner::Project, pick(project)));
+        }
+
+        let mut state = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+        if now.saturating_duration_since(state.swept)
+            >= self.limit.reads.period.max(self.limit.writes.period)
+        {
+            state.sweep(&self.limit, now);
+        }
+        let wait = owners
+            .iter()
+            .filter_map(|(owner, quota)| {
+                state
+                    .buckets
+                    .entry((owner.clone(), write))
+                    .or_insert_with(|| Bucket::new(*quota, now))
+                    .wait(*quota, now)
+            })
+            .max();
+        if wait.is_none() {
+            for (owner, _) in owners {
+                if let Some(bucket) = state.buckets.get_mut(&(owner, write)) {
+                    bucket.tokens -= 1.0;
+                }
+            }
+        }
+        drop(state);
+        wait
+    }
+}
+
+impl Buckets {
+    /// Remove the buckets of users who have been idle long enough for them to refill, so the map does not grow with
+    /// every user ever seen.
+    fn sweep(&mut self, limit: &RateLimit, now: Instant) {
+        self.buckets.retain(|(owner, write), bucket| {
+            let period = if *write {
+                limit.writes.period
+            } else {
+                limit.reads.period
+            };
+            *owner == Owner::Project || now.saturating_duration_since(bucket.updated) < period
+        });
+        self.swept = now;
+    }
+}
+
+/// A token bucket holding up to [`Quota::requests`] tokens.
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(quota: Quota, now: Instant) -> Self {
+        Self {
+            tokens: f64::from(quota.requests),
+            updated: now,
+        }
+    }
+
+    /// Refill the bucket up to `now`, and return how long until it holds a whole token, if it does not already.
+    fn wait(&mut self, quota: Quota, now: Instant) -> Option<Duration> {
+        let capacity = f64::from(quota.requests);
+        let rate = capacity / quota.period.as_secs_f64();
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = elapsed.mul_add(rate, self.tokens).min(capacity);
+        self.updated = now;
+        if self.tokens >= 1.0 {
+            None
+        } else {
+            Some(Duration::from_secs_f64((1.0 - self.tokens) / rate))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn users_have_separate_budgets() {
+        let limiter = RateLimiter::new(RateLimit::new(Quota::per_second(1), Quota::per_second(1)));
+        let now = Instant::now();
+        assert_eq!(limiter.try_acquire(Some("a"), false, now), None);
+        assert!(limiter.try_acquire(Some("a"), false, now).is_some());
+        assert_eq!(limiter.try_acquire(Some("a"), true, now), None);
+        assert_eq!(limiter.try_acquire(Some("b"), false, now), None);
+        assert_eq!(limiter.try_acquire(None, false, now), None);
+    }
+
+    #[test]
+    fn project_budget_is_shared_by_all_users() {
+        let limiter = RateLimiter::new(
+            RateLimit::new(Quota::per_second(5), Quota::per_second(5))
+                .project(Quota::per_second(2), Quota::per_second(5)),
+        );
+        let now = Instant::now();
+        assert_eq!(limiter.try_acquire(Some("a"), false, now), None);
+        assert_eq!(limiter.try_acquire(Some("b"), false, now), None);
+        let wait = limiter.try_acquire(Some("c"), false, now);
+        assert_eq!(wait, Some(Duration::from_millis(500)));
+        assert_eq!(limiter.try_acquire(Some("c"), true, now), None);
+        assert_eq!(
+            limiter.try_acquire(Some("c"), false, now + Duration::from_millis(500)),
+            None
+        );
+    }
+
+    #[test]
+    fn waiting_spends_no_budget() {
+        let limiter = RateLimiter::new(
+            RateLimit::new(Quota::per_second(1), Quota::per_second(1))
+                .project(Quota::per_second(2), Quota::per_second(2)),
+        );
+        let now = Instant::now();
+        assert_eq!(limiter.try_acquire(Some("a"), false, now), None);
+        assert!(limiter.try_acquire(Some("a"), false, now).is_some());
+        assert_eq!(limiter.try_acquire(Some("b"), false, now), None);
+    }
+
+    #[test]
+    fn idle_user_buckets_are_removed() {
+        let limiter = RateLimiter::new(
+            RateLimit::new(Quota::per_second(1), Quota::per_minute(1))
+                .project(Quota::per_second(10), Quota::per_second(10)),
+        );
+        let now = Instant::now();
+        limiter.try_acquire(Some("a"), false, now);
+        limiter.try_acquire(Some("b"), true, now);
+        limiter.try_acquire(Some("c"), false, now + Duration::from_millis(59_500));
+        assert_eq!(limiter.buckets.lock().unwrap().buckets.len(), 5);
+
+        limiter.try_acquire(None, false, now + Duration::from_mins(1));
+        let buckets = &limiter.buckets.lock().unwrap().buckets;
+        let mut owners: Vec<_> = buckets.keys().cloned().collect();
+        owners.sort_by_key(|(owner, write)| (format!("{owner:?}"), *write));
+        assert_eq!(
+            owners,
+            [
+                (Owner::Project, false),
+                (Owner::Project, true),
+                (Owner::User(None), false),
+                (Owner::User(Some("c".to_string())), false),
+            ]
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use reqwest::Method;
+
+use super::{Call, Client};
+use crate::model::{
+    registrations::{Registration, RegistrationCreate},
+    Empty,
+};
+
+impl Client {
+    /// Operations on `registrations`.
+    #[must_use]
+    pub const fn registrations(&self) -> Registrations<'_> {
+        Registrations { client: self }
+    }
+}
+
+/// Operations on `registrations`, created by [`Client::registrations`].
+#[derive(Debug, Clone, Copy)]
+pub struct Registrations<'a> {
+    client: &'a Client,
+}
+
+impl<'a> Registrations<'a> {
+    /// Register for push notifications from a feed. Registrations expire and must be created again before
+    /// its `expiryTime` to keep receiving notifications.
+    pub fn create(&self, registration: &RegistrationCreate) -> Call<'a, Registration> {
+        self.client
+            .request(Method::POST, &["registrations"])
+            .json(registration)
+    }
+
+    /// Delete a registration, stopping its notifications.
+    pub fn delete(&self, registration_id: &str) -> Call<'a, Empty> {
+        self.client
+            .request(Method::DELETE, &["registrations", registration_id])
+    }
+}
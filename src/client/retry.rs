@@ -0,0 +1,283 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::BuildHasher,
+    time::{Duration, SystemTime},
+};
+
+use reqwest::{header::HeaderMap, Method};
+
+use crate::Error;
+
+/// When and how often failed calls are retried.
+///
+/// A call is retried if it failed with one of the [retryable statuses](RetryPolicy::statuses), or could not reach the
+/// API because a connection failed or timed out. Only calls with an idempotent method (`GET`, `PUT` and `DELETE`) are
+/// retried, unless [`RetryPolicy::retry_non_idempotent`] is set or the call is marked with
+/// [`Call::idempotent`](super::Call::idempotent).
+///
+/// Between attempts the client waits for the time in the response's `Retry-After` header, or otherwise an exponential
+/// backoff starting from [`RetryPolicy::base_delay`]. Either way, it waits at most [`RetryPolicy::max_delay`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    statuses: Vec<u16>,
+    retry_non_idempotent: bool,
+}
+
+impl RetryPolicy {
+    /// A policy which sends every call exactly once.
+    #[must_use]
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// The number of times a call is sent, including the first attempt. Defaults to 5.
+    #[must_use]
+    pub const fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// The delay before the first retry, which doubles after every attempt. Defaults to 1 second.
+    #[must_use]
+    pub const fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// The longest wait between attempts, which also caps delays requested with `Retry-After`. Defaults to 32
+    /// seconds.
+    #[must_use]
+    pub const fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Whether to wait a random time up to the backoff, rather than exactly the backoff, so that clients which
+    /// failed together do not retry together. Defaults to `true`.
+    #[must_use]
+    pub const fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// The HTTP status codes which are retried. Defaults to 429, 500, 502, 503 and 504.
+    #[must_use]
+    pub fn statuses(mut self, statuses: &[u16]) -> Self {
+        self.statuses = statuses.to_vec();
+        self
+    }
+
+    /// Whether to also retry `POST` and `PATCH` calls, which may be applied twice if a response is lost. Defaults to
+    /// `false`.
+    #[must_use]
+    pub const fn retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    /// Whether a call with `method` may be retried at all.
+    pub(crate) const fn allows(&self, method: &Method, idempotent: bool) -> bool {
+        self.max_attempts > 1
+            && (idempotent
+                || self.retry_non_idempotent
+                || matches!(*method, Method::GET | Method::PUT | Method::DELETE))
+    }
+
+    /// How long to wait before sending attempt `attempt + 1`, or [`None`] if `error` should not be retried.
+    pub(crate) fn delay(
+        &self,
+        attempt: u32,
+        error: &Error,
+        retry_after: Option<Duration>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        let retryable = match error {
+            Error::Api(error) => self.statuses.contains(&error.code),
+            Error::Transport(error) => error.is_connect() || error.is_timeout(),
+            _ => false,
+        };
+        if !retryable {
+            return None;
+        }
+        if let Some(retry_after) = retry_after {
+            return Some(retry_after.min(self.max_delay));
+        }
+        let backoff = self
+            .base_delay
+            .saturating_mul(2_u32.saturating_pow(attempt - 1))
+            .min(self.max_delay);
+        if !self.jitter {
+            return Some(backoff);
+        }
+        let nanos = u64::try_from(backoff.as_nanos()).unwrap_or(u64::MAX);
+        let random = RandomState::new().hash_one(attempt);
+        Some(Duration::from_nanos(random % nanos.saturating_add(1)))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(32),
+            jitter: true,
+            statuses: vec![429, 500, 502, 503, 504],
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+/// The delay requested by a `Retry-After` header, given either in seconds or as an HTTP date.
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+    if let Ok(seconds) = value.trim().parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::{HeaderValue, RETRY_AFTER};
+
+    use super::*;
+    use crate::error::ApiError;
+
+    fn api_error(code: u16) -> Error {
+        Error::Api(ApiError::from_response(code, ""))
+    }
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::default().jitter(false)
+    }
+
+    #[test]
+    fn backs_off_exponentially_up_to_max_delay() {
+        let policy = policy().max_attempts(10);
+        let delays: Vec<_> = (1..8)
+            .map(|attempt| {
+                policy
+                    .delay(attempt, &api_error(503), None)
+                    .unwrap()
+                    .as_secs()
+            })
+            .collect();
+        assert_eq!(delays, [1, 2, 4, 8, 16, 32, 32]);
+    }
+
+    #[test]
+    fn stops_after_max_attempts() {
+        let policy = policy().max_attempts(3);
+        assert!(policy.delay(2, &api_error(503), None).is_some());
+        assert_eq!(policy.delay(3, &api_error(503), None), None);
+        assert_eq!(RetryPolicy::none().delay(1, &api_error(503), None), None);
+    }
+
+    #[test]
+    fn retries_only_listed_statuses() {
+        let policy = policy();
+        for code in [429, 500, 502, 503, 504] {
+            assert!(policy.delay(1, &api_error(code), None).is_some(), "{code}");
+        }
+        for code in [400, 401, 403, 404, 409] {
+            assert_eq!(policy.delay(1, &api_error(code), None), None, "{code}");
+        }
+        let policy = policy.statuses(&[409]);
+        assert!(policy.delay(1, &api_error(409), None).is_some());
+        assert_eq!(policy.delay(1, &api_error(503), None), None);
+        let source = serde_json::from_str::<()>("").unwrap_err();
+        let decode = Error::Decode {
+            source,
+            body: String::new(),
+        };
+        assert_eq!(policy.delay(1, &decode, None), None);
+    }
+
+    #[test]
+    fn retry_after_overrides_backoff() {
+        let policy = policy().max_delay(Duration::from_secs(100));
+        let retry_after = Some(Duration::from_secs(90));
+        assert_eq!(
+            policy.delay(1, &api_error(429), retry_after),
+            Some(Duration::from_secs(90))
+        );
+        assert_eq!(policy.delay(1, &api_error(400), retry_after), None);
+        assert_eq!(policy.delay(5, &api_error(429), retry_after), None);
+    }
+
+    #[test]
+    fn caps_retry_after_at_max_delay() {
+        let policy = policy().max_delay(Duration::from_secs(2));
+        let retry_after = Some(Duration::from_secs(90));
+        assert_eq!(
+            policy.delay(1, &api_error(429), retry_after),
+            Some(Duration::from_secs(2))
+        );
+    }
+
+    #[test]
+    fn jitter_stays_within_backoff() {
+        let policy = RetryPolicy::default();
+        for attempt in 1..5 {
+            let delay = policy.delay(attempt, &api_error(503), None).unwrap();
+            assert!(
+                delay <= Duration::from_secs(1 << (attempt - 1)),
+                "{delay:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn allows_idempotent_methods() {
+        let policy = RetryPolicy::default();
+        assert!(policy.allows(&Method::GET, false));
+        assert!(policy.allows(&Method::DELETE, false));
+        assert!(!policy.allows(&Method::POST, false));
+        assert!(policy.allows(&Method::POST, true));
+        assert!(RetryPolicy::default()
+            .retry_non_idempotent(true)
+            .allows(&Method::PATCH, false));
+        assert!(!RetryPolicy::none().allows(&Method::GET, true));
+    }
+
+    fn headers(value: &str) -> HeaderMap {
+        HeaderMap::from_iter([(RETRY_AFTER, HeaderValue::from_str(value).unwrap())])
+    }
+
+    #[test]
+    fn reads_retry_after_seconds() {
+        assert_eq!(retry_after(&headers("90")), Some(Duration::from_secs(90)));
+        assert_eq!(retry_after(&headers(" 5 ")), Some(Duration::from_secs(5)));
+        assert_eq!(retry_after(&HeaderMap::new()), None);
+        assert_eq!(retry_after(&headers("soon")), None);
+    }
+
+    #[test]
+    fn reads_retry_after_http_date() {
+        let date = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(90));
+        let delay = retry_after(&headers(&date)).unwrap();
+        assert!(
+            delay > Duration::from_secs(88) && delay <= Duration::from_secs(90),
+            "{delay:?}"
+        );
+        assert_eq!(
+            retry_after(&headers("Wed, 21 Oct 2015 07:28:00 GMT")),
+            Some(Duration::ZERO)
+        );
+    }
+}
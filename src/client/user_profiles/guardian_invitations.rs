@@ -0,0 +1,109 @@
+use reqwest::Method;
+
+use super::UserProfiles;
+use crate::{
+    client::{Call, Client, List},
+    model::user_profiles::{
+        guardian_invitations::{
+            GuardianInvitation, GuardianInvitationCreate, GuardianInvitationModify,
+            GuardianInvitationState, ListGuardianInvitationsResponse,
+        },
+        UserId,
+    },
+};
+
+impl<'a> UserProfiles<'a> {
+    /// Operations on the guardian invitations of a student.
+    ///
+    /// When listing, a `student_id` of `"-"` returns invitations for every student the requesting user may view.
+    #[must_use]
+    pub fn guardian_invitations(&self, student_id: impl Into<UserId>) -> GuardianInvitations<'a> {
+        GuardianInvitations {
+            client: self.client,
+            student_id: student_id.into().to_string(),
+        }
+    }
+}
+
+/// Operations on `userProfiles.guardianInvitations`, created by [`UserProfiles::guardian_invitations`].
+#[derive(Debug, Clone)]
+pub struct GuardianInvitations<'a> {
+    client: &'a Client,
+    student_id: String,
+}
+
+impl<'a> GuardianInvitations<'a> {
+    /// Invite a guardian for the student. An email is sent to the invited address.
+    pub fn create(&self, invitation: &GuardianInvitationCreate) -> Call<'a, GuardianInvitation> {
+        self.client
+            .request(
+                Method::POST,
+                &["userProfiles", &self.student_id, "guardianInvitations"],
+            )
+            .json(invitation)
+    }
+
+    /// Get a guardian invitation.
+    pub fn get(&self, invitation_id: &str) -> Call<'a, GuardianInvitation> {
+        self.client.request(
+            Method::GET,
+            &[
+                "userProfiles",
+                &self.student_id,
+                "guardianInvitations",
+                invitation_id,
+            ],
+        )
+    }
+
+    /// List the guardian invitations of the student.
+    pub fn list(&self) -> List<'a, ListGuardianInvitationsResponse> {
+        List::new(self.client.request(
+            Method::GET,
+            &["userProfiles", &self.student_id, "guardianInvitations"],
+        ))
+    }
+
+    /// Update the fields of a guardian invitation which are set in `invitation`.
+    pub fn patch(
+        &self,
+        invitation_id: &str,
+        invitation: &GuardianInvitationModify,
+    ) -> Call<'a, GuardianInvitation> {
+        self.client
+            .request(
+                Method::PATCH,
+                &[
+                    "userProfiles",
+                    &self.student_id,
+                    "guardianInvitations",
+                    invitation_id,
+                ],
+            )
+            .query(&[("updateMask", invitation.update_mask())])
+            .json(invitation)
+    }
+
+    /// Cancel a pending guardian invitation, by moving it to [`GuardianInvitationState::Complete`].
+    pub fn cancel(&self, invitation_id: &str) -> Call<'a, GuardianInvitation> {
+        self.patch(
+            invitation_id,
+            &GuardianInvitationModify {
+                state: Some(GuardianInvitationState::Complete),
+            },
+        )
+    }
+}
+
+impl List<'_, ListGuardianInvitationsResponse> {
+    /// Only return invitations sent to this email address.
+    pub fn invited_email_address(self, email: &str) -> Self {
+        self.query(&[("invitedEmailAddress", email)])
+    }
+
+    /// Only return invitations in one of these states.
+    pub fn states(self, states: &[GuardianInvitationState]) -> Self {
+        let query: Vec<_> = states.iter().map(|state| ("states", state)).collect();
+        self.query(&query)
+    }
+}
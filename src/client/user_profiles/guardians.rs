@@ -0,0 +1,66 @@
+use reqwest::Method;
+
+use super::UserProfiles;
+use crate::{
+    client::{Call, Client, List},
+    model::{
+        user_profiles::{
+            guardians::{Guardian, ListGuardiansResponse},
+            UserId,
+        },
+        Empty,
+    },
+};
+
+impl<'a> UserProfiles<'a> {
+    /// Operations on the guardians of a student.
+    ///
+    /// When listing, a `student_id` of `"-"` returns guardians of every student the requesting user may view.
+    #[must_use]
+    pub fn guardians(&self, student_id: impl Into<UserId>) -> Guardians<'a> {
+        Guardians {
+            client: self.client,
+            student_id: student_id.into().to_string(),
+        }
+    }
+}
+
+/// Operations on `userProfiles.guardians`, created by [`UserProfiles::guardians`].
+#[derive(Debug, Clone)]
+pub struct Guardians<'a> {
+    client: &'a Client,
+    student_id: String,
+}
+
+impl<'a> Guardians<'a> {
+    /// Get a guardian of the student.
+    pub fn get(&self, guardian_id: &str) -> Call<'a, Guardian> {
+        self.client.request(
+            Method::GET,
+            &["userProfiles", &self.student_id, "guardians", guardian_id],
+        )
+    }
+
+    /// List the guardians of the student.
+    pub fn list(&self) -> List<'a, ListGuardiansResponse> {
+        List::new(self.client.request(
+            Method::GET,
+            &["userProfiles", &self.student_id, "guardians"],
+        ))
+    }
+
+    /// Remove a guardian from the student. The guardian stops receiving guardian notifications.
+    pub fn delete(&self, guardian_id: &str) -> Call<'a, Empty> {
+        self.client.request(
+            Method::DELETE,
+            &["userProfiles", &self.student_id, "guardians", guardian_id],
+        )
+    }
+}
+
+impl List<'_, ListGuardiansResponse> {
+    /// Only return guardians whose invitation was sent to this email address. Only domain administrators may set this.
+    pub fn invited_email_address(self, email: &str) -> Self {
+        self.query(&[("invitedEmailAddress", email)])
+    }
+}
@@ -0,0 +1,30 @@
+use reqwest::Method;
+
+pub mod guardian_invitations;
+pub mod guardians;
+
+use super::{Call, Client};
+use crate::model::user_profiles::{UserId, UserProfile};
+
+impl Client {
+    /// Operations on `userProfiles`.
+    #[must_use]
+    pub const fn user_profiles(&self) -> UserProfiles<'_> {
+        UserProfiles { client: self }
+    }
+}
+
+/// Operations on `userProfiles`, created by [`Client::user_profiles`].
+#[derive(Debug, Clone, Copy)]
+#[allow(clippy::module_name_repetitions)]
+pub struct UserProfiles<'a> {
+    client: &'a Client,
+}
+
+impl<'a> UserProfiles<'a> {
+    /// Get a user's profile, by numeric identifier, email address, or `"me"` for the requesting user.
+    pub fn get(&self, user_id: impl Into<UserId>) -> Call<'a, UserProfile> {
+        self.client
+            .request(Method::GET, &["userProfiles", user_id.into().as_str()])
+    }
+}
@@ -0,0 +1,303 @@
+//! Errors returned by the [`Client`](crate::Client).
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// Result type returned by every [`Client`](crate::Client) call.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Everything that can go wrong when calling the Classroom API.
+#[derive(Debug)]
+pub enum Error {
+    /// The request could not be sent, or the response could not be read.
+    Transport(reqwest::Error),
+    /// The API responded successfully, but the body did not match the expected type.
+    Decode {
+        /// Why the body could not be deserialized.
+        source: serde_json::Error,
+        /// The raw response body.
+        body: String,
+    },
+    /// The API responded with an error.
+    Api(ApiError),
+    /// No access token could be obtained.
+    Auth(AuthError),
+    /// A [`TokenStore`](crate::auth::TokenStore) failed to load or save a token.
+    TokenStore(crate::auth::BoxError),
+    /// A batch request failed as a whole, its response was malformed, or it had no response for a call in the batch.
+    Batch(String),
+}
+
+impl Error {
+    /// The error returned by the API, if this is one.
+    #[must_use]
+    pub const fn api(&self) -> Option<&ApiError> {
+        match self {
+            Self::Api(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Whether the requested resource does not exist, for example an unknown course.
+    #[must_use]
+    pub fn is_not_found(&self) -> bool {
+        self.api()
+            .is_some_and(|error| error.status == Status::NotFound)
+    }
+
+    /// Whether the requesting user is not allowed to perform the request.
+    #[must_use]
+    pub fn is_permission_denied(&self) -> bool {
+        self.api()
+            .is_some_and(|error| error.status == Status::PermissionDenied)
+    }
+
+    /// Whether the resource being created already exists, for example an alias that is already taken.
+    #[must_use]
+    pub fn is_already_exists(&self) -> bool {
+        self.api()
+            .is_some_and(|error| error.status == Status::AlreadyExists)
+    }
+
+    /// Whether a quota or rate limit was exceeded.
+    #[must_use]
+    pub fn is_rate_limited(&self) -> bool {
+        self.api().is_some_and(ApiError::is_rate_limited)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Transport(source) => write!(f, "transport error: {source}"),
+            Self::Decode { source, .. } => write!(f, "could not decode response: {source}"),
+            Self::Api(error) => error.fmt(f),
+            Self::Auth(error) => error.fmt(f),
+            Self::TokenStore(source) => write!(f, "token store error: {source}"),
+            Self::Batch(message) => write!(f, "batch error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Transport(source) => Some(source),
+            Self::Decode { source, .. } => Some(source),
+            Self::Api(error) => Some(error),
+            Self::Auth(error) => Some(error),
+            Self::TokenStore(source) => Some(source.as_ref()),
+            Self::Batch(_) => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(source: reqwest::Error) -> Self {
+        Self::Transport(source)
+    }
+}
+
+impl From<ApiError> for Error {
+    fn from(error: ApiError) -> Self {
+        Self::Api(error)
+    }
+}
+
+/// An error returned by a Google API, from the standard `{"error": {...}}` envelope.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct ApiError {
+    /// HTTP status code of the response.
+    pub code: u16,
+    /// Developer-facing description of the error.
+    pub message: String,
+    /// Canonical error code.
+    #[serde(default)]
+    pub status: Status,
+    /// Additional error details, such as `google.rpc.ErrorInfo`. Each entry is tagged by its `@type`.
+    #[serde(default)]
+    pub details: Vec<serde_json::Value>,
+}
+
+impl ApiError {
+    /// Parse an error response. Bodies that are not a Google error envelope, such as proxy error pages, are kept as
+    /// the message. The status is inferred from `code` when the body does not name a known one.
+    #[must_use]
+    pub fn from_response(code: u16, body: &str) -> Self {
+        #[derive(Deserialize)]
+        struct Envelope {
+            error: ApiError,
+        }
+
+        serde_json::from_str::<Envelope>(body).map_or_else(
+            |_| Self {
+                code,
+                message: body.to_owned(),
+                status: Status::from_http(code),
+                details: Vec::new(),
+            },
+            |envelope| {
+                let mut error = envelope.error;
+                if error.status == Status::Unknown {
+                    error.status = Status::from_http(code);
+                }
+                error
+            },
+        )
+    }
+
+    /// The `reason` of every `google.rpc.ErrorInfo` entry in [`ApiError::details`].
+    pub fn reasons(&self) -> impl Iterator<Item = &str> {
+        self.details
+            .iter()
+            .filter_map(|detail| detail.get("reason")?.as_str())
+    }
+
+    /// Whether a quota or rate limit was exceeded.
+    #[must_use]
+    pub fn is_rate_limited(&self) -> bool {
+        self.code == 429
+            || self.status == Status::ResourceExhausted
+            || self.reasons().any(|reason| {
+                matches!(
+                    reason,
+                    "RATE_LIMIT_EXCEEDED" | "rateLimitExceeded" | "userRateLimitExceeded"
+                )
+            })
+    }
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {:?}: {}", self.code, self.status, self.message)
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// An OAuth 2.0 token endpoint refused to issue an access token.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct AuthError {
+    /// Error code, such as `invalid_grant`.
+    pub error: String,
+    /// Human-readable description of the error.
+    pub error_description: Option<String>,
+}
+
+impl Display for AuthError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.error_description {
+            Some(description) => write!(f, "authentication failed: {}: {description}", self.error),
+            None => write!(f, "authentication failed: {}", self.error),
+        }
+    }
+}
+
+impl std::error::Error for AuthError {}
+
+/// Canonical error codes used by Google APIs.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Status {
+    Ok,
+    Cancelled,
+    InvalidArgument,
+    DeadlineExceeded,
+    NotFound,
+    AlreadyExists,
+    PermissionDenied,
+    ResourceExhausted,
+    FailedPrecondition,
+    Aborted,
+    OutOfRange,
+    Unimplemented,
+    Internal,
+    Unavailable,
+    DataLoss,
+    Unauthenticated,
+    /// Unknown error, or a status this crate does not recognise.
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+impl Status {
+    /// The status usually returned alongside an HTTP status code.
+    #[must_use]
+    pub const fn from_http(code: u16) -> Self {
+        match code {
+            200..=299 => Self::Ok,
+            400 => Self::InvalidArgument,
+            401 => Self::Unauthenticated,
+            403 => Self::PermissionDenied,
+            404 => Self::NotFound,
+            409 => Self::AlreadyExists,
+            429 => Self::ResourceExhausted,
+            499 => Self::Cancelled,
+            501 => Self::Unimplemented,
+            503 => Self::Unavailable,
+            504 => Self::DeadlineExceeded,
+            500..=599 => Self::Internal,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENVELOPE: &str = r#"{
+        "error": {
+            "code": 429,
+            "message": "Quota exceeded for quota metric 'Requests'.",
+            "status": "RESOURCE_EXHAUSTED",
+            "details": [
+                {
+                    "@type": "type.googleapis.com/google.rpc.ErrorInfo",
+                    "reason": "RATE_LIMIT_EXCEEDED",
+                    "domain": "googleapis.com"
+                }
+            ]
+        }
+    }"#;
+
+    #[test]
+    fn parses_error_envelope() {
+        let error = ApiError::from_response(429, ENVELOPE);
+        assert_eq!(error.code, 429);
+        assert_eq!(error.message, "Quota exceeded for quota metric 'Requests'.");
+        assert_eq!(error.status, Status::ResourceExhausted);
+        assert_eq!(error.reasons().collect::<Vec<_>>(), ["RATE_LIMIT_EXCEEDED"]);
+        assert!(error.is_rate_limited());
+    }
+
+    #[test]
+    fn infers_unknown_status_from_code() {
+        let body = r#"{"error": {"code": 404, "message": "Requested entity was not found."}}"#;
+        let error = ApiError::from_response(404, body);
+        assert_eq!(error.status, Status::NotFound);
+        assert!(error.details.is_empty());
+    }
+
+    #[test]
+    fn keeps_non_json_body_as_message() {
+        let error = ApiError::from_response(502, "<html>Bad Gateway</html>");
+        assert_eq!(error.code, 502);
+        assert_eq!(error.message, "<html>Bad Gateway</html>");
+        assert_eq!(error.status, Status::Internal);
+    }
+
+    #[test]
+    fn status_helpers() {
+        let error = |code| Error::from(ApiError::from_response(code, ""));
+        assert!(error(404).is_not_found());
+        assert!(error(403).is_permission_denied());
+        assert!(error(409).is_already_exists());
+        assert!(error(429).is_rate_limited());
+        assert!(!error(404).is_permission_denied());
+        assert!(!error(500).is_rate_limited());
+    }
+}
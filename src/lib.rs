@@ -1,9 +1,21 @@
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 #![cfg_attr(all(doc, CHANNEL_NIGHTLY), feature(doc_auto_cfg))]
 
+#[cfg(feature = "client")]
+pub mod auth;
+#[cfg(feature = "client")]
+pub mod client;
+#[cfg(feature = "client")]
+pub mod error;
 pub mod model;
+pub mod notifications;
+#[cfg(all(test, feature = "client"))]
+mod test_server;
+
+#[cfg(feature = "client")]
+pub use client::{Call, Client, ClientBuilder, List, RateLimit, RetryPolicy};
+#[cfg(feature = "client")]
+pub use error::{Error, Result};
 
 pub const API_VERSION: u8 = 1;
 pub const SERVICE_ENDPOINT: &str = "https://classroom.googleapis.com";
-
-pub struct Client {}
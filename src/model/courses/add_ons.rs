@@ -0,0 +1,194 @@
+use serde::{Deserialize, Serialize};
+
+use super::course_work::submissions::SubmissionState;
+use crate::model::{Date, Page, TimeOfDay};
+
+/// An add-on attachment on a post: course work, an announcement or a course work material.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddOnAttachment {
+    /// Identifier of the course.
+    pub course_id: String,
+    /// Identifier of the announcement, course work or course work material under which the attachment is attached. Unique per course.
+    pub item_id: String,
+    /// Classroom-assigned identifier for this attachment, unique per post.
+    pub id: String,
+    /// Title of this attachment. The title must be between 1 and 1000 characters.
+    pub title: String,
+    /// URI to show the teacher view of the attachment. The URI will be opened in an iframe with the `courseId`, `itemId`, `itemType`, and `attachmentId` query parameters set.
+    pub teacher_view_uri: EmbedUri,
+    /// URI to show the student view of the attachment. The URI will be opened in an iframe with the `courseId`, `itemId`, `itemType`, and `attachmentId` query parameters set.
+    pub student_view_uri: EmbedUri,
+    /// URI for the teacher to see student work on the attachment, if applicable. The URI will be opened in an iframe with the `courseId`, `itemId`, `itemType`, `attachmentId`, and `submissionId` query parameters set.
+    pub student_work_review_uri: Option<EmbedUri>,
+    /// Date, in UTC, that work on this attachment is due. This must be specified if `due_time` is specified.
+    pub due_date: Option<Date>,
+    /// Time of day, in UTC, that work on this attachment is due. This must be specified if `due_date` is specified.
+    pub due_time: Option<TimeOfDay>,
+    /// Maximum grade for this attachment. Can only be set if `student_work_review_uri` is set. Set to a non-zero value to indicate that the attachment supports grade passback. If set, this must be a non-negative integer value.
+    pub max_points: Option<f64>,
+    /// Identifiers of attachments that were previous copies of this attachment, if it was copied by the Classroom UI.
+    #[serde(default)]
+    pub copy_history: Vec<CopyHistory>,
+}
+
+/// URI to be iframed after being populated with query parameters.
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EmbedUri {
+    /// URI to be iframed after being populated with query parameters. This must be a valid UTF-8 string containing between 1 and 1800 characters.
+    pub uri: String,
+}
+
+/// Identifier of a previous copy of a given attachment.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CopyHistory {
+    /// Identifier of the course.
+    pub course_id: String,
+    /// Identifier of the announcement, course work or course work material.
+    pub item_id: String,
+    /// Identifier of the attachment.
+    pub attachment_id: String,
+}
+
+/// Create an add-on attachment.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct AddOnAttachmentCreate {
+    /// Title of this attachment. The title must be between 1 and 1000 characters.
+    pub title: String,
+    /// URI to show the teacher view of the attachment.
+    pub teacher_view_uri: EmbedUri,
+    /// URI to show the student view of the attachment.
+    pub student_view_uri: EmbedUri,
+    /// URI for the teacher to see student work on the attachment, if applicable.
+    pub student_work_review_uri: Option<EmbedUri>,
+    /// Date, in UTC, that work on this attachment is due.
+    pub due_date: Option<Date>,
+    /// Time of day, in UTC, that work on this attachment is due.
+    pub due_time: Option<TimeOfDay>,
+    /// Maximum grade for this attachment, which enables grade passback.
+    pub max_points: Option<f64>,
+}
+
+/// Modify an add-on attachment.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct AddOnAttachmentModify {
+    /// Title of this attachment.
+    pub title: Option<String>,
+    /// URI to show the teacher view of the attachment.
+    pub teacher_view_uri: Option<EmbedUri>,
+    /// URI to show the student view of the attachment.
+    pub student_view_uri: Option<EmbedUri>,
+    /// URI for the teacher to see student work on the attachment.
+    pub student_work_review_uri: Option<EmbedUri>,
+    /// Date, in UTC, that work on this attachment is due.
+    pub due_date: Option<Date>,
+    /// Time of day, in UTC, that work on this attachment is due.
+    pub due_time: Option<TimeOfDay>,
+    /// Maximum grade for this attachment.
+    pub max_points: Option<f64>,
+}
+
+impl AddOnAttachmentModify {
+    /// The `updateMask` for this modification: the API names of every field that is [`Some`], comma-separated.
+    #[must_use]
+    pub fn update_mask(&self) -> String {
+        crate::model::update_mask(&[
+            ("title", self.title.is_some()),
+            ("teacherViewUri", self.teacher_view_uri.is_some()),
+            ("studentViewUri", self.student_view_uri.is_some()),
+            (
+                "studentWorkReviewUri",
+                self.student_work_review_uri.is_some(),
+            ),
+            ("dueDate", self.due_date.is_some()),
+            ("dueTime", self.due_time.is_some()),
+            ("maxPoints", self.max_points.is_some()),
+        ])
+    }
+}
+
+/// Attachment-relevant metadata for Classroom add-ons in the context of a specific post.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddOnContext {
+    /// Immutable. Identifier of the course.
+    pub course_id: String,
+    /// Immutable. Identifier of the announcement, course work or course work material under which the attachment is attached.
+    pub item_id: String,
+    /// Whether the post allows the teacher to see student work and passback grades.
+    #[serde(default)]
+    pub supports_student_work: bool,
+    /// Add-on context corresponding to the requesting user's role as a student. Its presence implies that the requesting user is a student in the course.
+    pub student_context: Option<StudentContext>,
+    /// Add-on context corresponding to the requesting user's role as a teacher. Its presence implies that the requesting user is a teacher in the course.
+    pub teacher_context: Option<TeacherContext>,
+}
+
+/// Role-specific context if the requesting user is a student.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StudentContext {
+    /// Requesting user's submission id to be used for grade passback and to identify the student when showing student work to the teacher. This is set exactly when `supports_student_work` is true.
+    pub submission_id: Option<String>,
+}
+
+/// Role-specific context if the requesting user is a teacher.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TeacherContext {}
+
+/// Payload for grade update requests on an add-on attachment.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddOnAttachmentStudentSubmission {
+    /// Submission state of the student's course work.
+    pub post_submission_state: SubmissionState,
+    /// Student grade on this attachment. If unset, no grade was set.
+    pub points_earned: Option<f64>,
+}
+
+/// Modify a student submission for an add-on attachment.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddOnAttachmentStudentSubmissionModify {
+    /// Student grade on this attachment, passed back to Classroom. Must not exceed the attachment's `max_points`.
+    pub points_earned: Option<f64>,
+}
+
+impl AddOnAttachmentStudentSubmissionModify {
+    /// The `updateMask` for this modification: the API names of every field that is [`Some`], comma-separated.
+    #[must_use]
+    pub fn update_mask(&self) -> String {
+        crate::model::update_mask(&[("pointsEarned", self.points_earned.is_some())])
+    }
+}
+
+/// One page of add-on attachments, as returned by `addOnAttachments.list`.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct ListAddOnAttachmentsResponse {
+    /// Attachments under the given post.
+    #[serde(default)]
+    pub add_on_attachments: Vec<AddOnAttachment>,
+    /// Token identifying the next page of results to return. If empty, no further results are available.
+    pub next_page_token: Option<String>,
+}
+
+impl Page for ListAddOnAttachmentsResponse {
+    type Item = AddOnAttachment;
+
+    fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.add_on_attachments
+    }
+}
@@ -1 +1,232 @@
+use std::{fmt, str::FromStr};
 
+use serde::{de::Visitor, Deserialize, Serialize};
+
+use crate::model::Page;
+
+/// Alternative identifier for a course.
+///
+/// An alias uniquely identifies a course. It must be unique within one of the following scopes:
+/// - domain: A domain-scoped alias is visible to all users within the alias creator's domain and can be created only
+///   by a domain admin. A domain-scoped alias is often used when a course has an identifier external to Classroom.
+/// - project: A project-scoped alias is visible to any request from an application using the Developer Console
+///   project ID that created the alias and can be created by any project. A project-scoped alias is often used when
+///   an application has alternative identifiers. A random value can also be used to avoid duplicate courses in the
+///   event of transmission failures, as retrying a request will return `ALREADY_EXISTS` if a previous one has
+///   succeeded.
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct CourseAlias {
+    /// Alias string.
+    pub alias: AliasScope,
+}
+
+/// A course identifier, or an alias in one of the scopes described on [`CourseAlias`].
+///
+/// This is accepted anywhere a course identifier is, and [`From<&str>`] parses the `d:` and `p:` prefixes, so
+/// `"d:math101"` and `"123456"` can be used interchangeably. Use [`AliasScope::parse`] to reject strings which are
+/// neither an alias nor a numeric identifier.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[allow(clippy::module_name_repetitions)]
+pub enum AliasScope {
+    /// Domain-scoped alias, written `d:<name>`.
+    Domain(String),
+    /// Project-scoped alias, written `p:<name>`.
+    Project(String),
+    /// Classroom-assigned numeric course identifier (not an alias).
+    Id(String),
+}
+
+impl AliasScope {
+    /// Parse an alias with a `d:` or `p:` prefix, or a numeric course identifier.
+    ///
+    /// # Errors
+    /// Errors if `value` is an alias with an empty name, or is neither prefixed nor numeric.
+    pub fn parse(value: &str) -> Result<Self, ParseAliasScopeError> {
+        let invalid = || ParseAliasScopeError(value.to_string());
+        if let Some(name) = value.strip_prefix("d:") {
+            return (!name.is_empty())
+                .then(|| Self::Domain(name.to_string()))
+                .ok_or_else(invalid);
+        }
+        if let Some(name) = value.strip_prefix("p:") {
+            return (!name.is_empty())
+                .then(|| Self::Project(name.to_string()))
+                .ok_or_else(invalid);
+        }
+        if !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Ok(Self::Id(value.to_string()));
+        }
+        Err(invalid())
+    }
+
+    /// Parse `value` for use as a course identifier in a request, leaving any string [`AliasScope::parse`] rejects
+    /// as an [`AliasScope::Id`] for the API to report.
+    fn lenient(value: &str) -> Self {
+        Self::parse(value).unwrap_or_else(|_| Self::Id(value.to_string()))
+    }
+}
+
+impl fmt::Display for AliasScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Domain(name) => write!(f, "d:{name}"),
+            Self::Project(name) => write!(f, "p:{name}"),
+            Self::Id(id) => f.write_str(id),
+        }
+    }
+}
+
+impl FromStr for AliasScope {
+    type Err = ParseAliasScopeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl From<&str> for AliasScope {
+    fn from(value: &str) -> Self {
+        Self::lenient(value)
+    }
+}
+
+impl From<&String> for AliasScope {
+    fn from(value: &String) -> Self {
+        Self::lenient(value)
+    }
+}
+
+impl From<String> for AliasScope {
+    fn from(value: String) -> Self {
+        Self::lenient(&value)
+    }
+}
+
+impl From<&Self> for AliasScope {
+    fn from(value: &Self) -> Self {
+        value.clone()
+    }
+}
+
+/// A string is neither a `d:` or `p:` alias nor a numeric course identifier, from [`AliasScope::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseAliasScopeError(pub String);
+
+impl fmt::Display for ParseAliasScopeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is neither a course alias nor a course identifier",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseAliasScopeError {}
+
+impl Serialize for AliasScope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+struct AliasScopeVisitor;
+
+impl Visitor<'_> for AliasScopeVisitor {
+    type Value = AliasScope;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a course alias or identifier")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        AliasScope::parse(v).map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for AliasScope {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(AliasScopeVisitor)
+    }
+}
+
+/// One page of aliases, as returned by `courses.aliases.list`.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct ListCourseAliasesResponse {
+    /// The course aliases.
+    #[serde(default)]
+    pub aliases: Vec<CourseAlias>,
+    /// Token identifying the next page of results to return. If empty, no further results are available.
+    pub next_page_token: Option<String>,
+}
+
+impl Page for ListCourseAliasesResponse {
+    type Item = CourseAlias;
+
+    fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.aliases
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_scopes() {
+        assert_eq!(
+            AliasScope::parse("d:math101"),
+            Ok(AliasScope::Domain("math101".to_string()))
+        );
+        assert_eq!(
+            AliasScope::parse("p:sis-4567"),
+            Ok(AliasScope::Project("sis-4567".to_string()))
+        );
+        assert_eq!(
+            AliasScope::parse("123456"),
+            Ok(AliasScope::Id("123456".to_string()))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_scopes() {
+        for value in ["", "d:", "p:", "math101", "12a34", "x:math101"] {
+            assert_eq!(
+                AliasScope::parse(value),
+                Err(ParseAliasScopeError(value.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn round_trips_through_display() {
+        for value in ["d:math101", "p:sis-4567", "123456"] {
+            assert_eq!(AliasScope::parse(value).unwrap().to_string(), value);
+        }
+    }
+
+    #[test]
+    fn keeps_unparsable_strings_as_ids() {
+        assert_eq!(
+            AliasScope::from("math101"),
+            AliasScope::Id("math101".to_string())
+        );
+    }
+}
@@ -1 +1,127 @@
+use serde::{Deserialize, Serialize};
 
+use crate::model::{AssigneeMode, IndividualStudentsOptions, Material, Page, StudentGroupsOptions};
+
+/// Announcement created by a teacher for students of the course.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Announcement {
+    /// Identifier of the course.
+    pub course_id: String,
+    /// Classroom-assigned identifier of this announcement, unique per course.
+    pub id: String,
+    /// Description of this announcement. The text must be a valid UTF-8 string containing no more than 30,000 characters.
+    pub text: Option<String>,
+    /// Additional materials.
+    #[serde(default)]
+    pub materials: Vec<Material>,
+    /// Status of this announcement. If unspecified, the default state is [`AnnouncementState::Draft`].
+    pub state: Option<AnnouncementState>,
+    /// Absolute link to this announcement in the Classroom web UI. This is only populated if state is [`AnnouncementState::Published`].
+    pub alternate_link: Option<String>,
+    /// Timestamp when this announcement was created.
+    #[cfg(feature = "chrono")]
+    pub creation_time: chrono::DateTime<chrono::Utc>,
+    /// Timestamp of the most recent change to this announcement.
+    #[cfg(feature = "chrono")]
+    pub update_time: chrono::DateTime<chrono::Utc>,
+    /// Optional timestamp when this announcement is scheduled to be published.
+    #[cfg(feature = "chrono")]
+    pub scheduled_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Assignee mode of the announcement. If unspecified, the default value is [`AssigneeMode::AllStudents`].
+    pub assignee_mode: Option<AssigneeMode>,
+    /// Identifiers of students with access to the announcement. If the assignee mode is [`AssigneeMode::IndividiualStudents`], then only students specified in this field can see the announcement.
+    pub individual_students_options: Option<IndividualStudentsOptions>,
+    /// Identifiers of student groups with access to the announcement. If the assignee mode is [`AssigneeMode::StudentGroups`], then only members of these groups can see the announcement.
+    pub student_groups_options: Option<StudentGroupsOptions>,
+    /// Identifier for the user that created the announcement.
+    pub creator_user_id: String,
+}
+
+/// Create an announcement.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct AnnouncementCreate {
+    /// Description of this announcement. The text must be a valid UTF-8 string containing no more than 30,000 characters.
+    pub text: String,
+    /// Additional materials.
+    pub materials: Vec<Material>,
+    /// Status of this announcement. If unspecified, the default state is [`AnnouncementState::Draft`].
+    pub state: Option<AnnouncementState>,
+    /// Optional timestamp when this announcement is scheduled to be published.
+    #[cfg(feature = "chrono")]
+    pub scheduled_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Assignee mode of the announcement. If unspecified, the default value is [`AssigneeMode::AllStudents`].
+    pub assignee_mode: Option<AssigneeMode>,
+    /// Identifiers of students with access to the announcement. Only used when the assignee mode is [`AssigneeMode::IndividiualStudents`].
+    pub individual_students_options: Option<IndividualStudentsOptions>,
+    /// Identifiers of student groups with access to the announcement. Only used when the assignee mode is [`AssigneeMode::StudentGroups`].
+    pub student_groups_options: Option<StudentGroupsOptions>,
+}
+
+/// Modify an announcement.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct AnnouncementModify {
+    /// Description of this announcement. The text must be a valid UTF-8 string containing no more than 30,000 characters.
+    pub text: Option<String>,
+    /// Status of this announcement. An announcement may only be moved from [`AnnouncementState::Draft`] to [`AnnouncementState::Published`].
+    pub state: Option<AnnouncementState>,
+    /// Optional timestamp when this announcement is scheduled to be published.
+    #[cfg(feature = "chrono")]
+    pub scheduled_time: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl AnnouncementModify {
+    /// The `updateMask` for this modification: the API names of every field that is [`Some`], comma-separated.
+    #[must_use]
+    pub fn update_mask(&self) -> String {
+        crate::model::update_mask(&[
+            ("text", self.text.is_some()),
+            ("state", self.state.is_some()),
+            #[cfg(feature = "chrono")]
+            ("scheduledTime", self.scheduled_time.is_some()),
+        ])
+    }
+}
+
+/// Possible states an announcement can be in.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(clippy::module_name_repetitions, clippy::enum_variant_names)]
+pub enum AnnouncementState {
+    /// No state specified. This is never returned.
+    AnnouncementStateUnspecified,
+    /// Status for announcement that has been published. This is the default state.
+    Published,
+    /// Status for an announcement that is not yet published. Announcement in this state is visible only to course teachers and domain administrators.
+    Draft,
+    /// Status for announcement that was published but is now deleted. Announcement in this state is visible only to course teachers and domain administrators. Announcement in this state is deleted after some time.
+    Deleted,
+}
+
+/// One page of announcements, as returned by `courses.announcements.list`.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct ListAnnouncementsResponse {
+    /// Announcement items that match the request.
+    #[serde(default)]
+    pub announcements: Vec<Announcement>,
+    /// Token identifying the next page of results to return. If empty, no further results are available.
+    pub next_page_token: Option<String>,
+}
+
+impl Page for ListAnnouncementsResponse {
+    type Item = Announcement;
+
+    fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.announcements
+    }
+}
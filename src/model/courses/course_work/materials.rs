@@ -1 +1,146 @@
+use serde::{Deserialize, Serialize};
 
+use crate::model::{
+    courses::topics::TopicId, AssigneeMode, IndividualStudentsOptions, Material, Page,
+    StudentGroupsOptions,
+};
+
+/// Course work material created by a teacher for students of the course.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct CourseWorkMaterial {
+    /// Identifier of the course.
+    pub course_id: String,
+    /// Classroom-assigned identifier of this course work material, unique per course.
+    pub id: String,
+    /// Title of this course work material. The title must be a valid UTF-8 string containing between 1 and 3000 characters.
+    pub title: String,
+    /// Optional description of this course work material. The text must be a valid UTF-8 string containing no more than 30,000 characters.
+    pub description: Option<String>,
+    /// Additional materials. A course work material must have no more than 20 material items.
+    #[serde(default)]
+    pub materials: Vec<Material>,
+    /// Status of this course work material. If unspecified, the default state is [`CourseWorkMaterialState::Draft`].
+    pub state: Option<CourseWorkMaterialState>,
+    /// Absolute link to this course work material in the Classroom web UI. This is only populated if state is [`CourseWorkMaterialState::Published`].
+    pub alternate_link: Option<String>,
+    /// Timestamp when this course work material was created.
+    #[cfg(feature = "chrono")]
+    pub creation_time: chrono::DateTime<chrono::Utc>,
+    /// Timestamp of the most recent change to this course work material.
+    #[cfg(feature = "chrono")]
+    pub update_time: chrono::DateTime<chrono::Utc>,
+    /// Optional timestamp when this course work material is scheduled to be published.
+    #[cfg(feature = "chrono")]
+    pub scheduled_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Assignee mode of the course work material. If unspecified, the default value is [`AssigneeMode::AllStudents`].
+    pub assignee_mode: Option<AssigneeMode>,
+    /// Identifiers of students with access to the course work material. If the assignee mode is [`AssigneeMode::IndividiualStudents`], then only students specified in this field can see the course work material.
+    pub individual_students_options: Option<IndividualStudentsOptions>,
+    /// Identifiers of student groups with access to the course work material. If the assignee mode is [`AssigneeMode::StudentGroups`], then only members of these groups can see the course work material.
+    pub student_groups_options: Option<StudentGroupsOptions>,
+    /// Identifier for the user that created the course work material.
+    pub creator_user_id: String,
+    /// Identifier for the topic that this course work material is associated with. Must match an existing topic in the course.
+    pub topic_id: Option<TopicId>,
+}
+
+/// Create a course work material.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct CourseWorkMaterialCreate {
+    /// Title of this course work material. The title must be a valid UTF-8 string containing between 1 and 3000 characters.
+    pub title: String,
+    /// Optional description of this course work material. The text must be a valid UTF-8 string containing no more than 30,000 characters.
+    pub description: Option<String>,
+    /// Additional materials. A course work material must have no more than 20 material items.
+    pub materials: Vec<Material>,
+    /// Status of this course work material. If unspecified, the default state is [`CourseWorkMaterialState::Draft`].
+    pub state: Option<CourseWorkMaterialState>,
+    /// Optional timestamp when this course work material is scheduled to be published.
+    #[cfg(feature = "chrono")]
+    pub scheduled_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Assignee mode of the course work material. If unspecified, the default value is [`AssigneeMode::AllStudents`].
+    pub assignee_mode: Option<AssigneeMode>,
+    /// Identifiers of students with access to the course work material. Only used when the assignee mode is [`AssigneeMode::IndividiualStudents`].
+    pub individual_students_options: Option<IndividualStudentsOptions>,
+    /// Identifiers of student groups with access to the course work material. Only used when the assignee mode is [`AssigneeMode::StudentGroups`].
+    pub student_groups_options: Option<StudentGroupsOptions>,
+    /// Identifier for the topic that this course work material is associated with. Must match an existing topic in the course.
+    pub topic_id: Option<TopicId>,
+}
+
+/// Modify a course work material.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct CourseWorkMaterialModify {
+    /// Title of this course work material.
+    pub title: Option<String>,
+    /// Optional description of this course work material.
+    pub description: Option<String>,
+    /// Status of this course work material. A course work material may only be moved from [`CourseWorkMaterialState::Draft`] to [`CourseWorkMaterialState::Published`].
+    pub state: Option<CourseWorkMaterialState>,
+    /// Optional timestamp when this course work material is scheduled to be published.
+    #[cfg(feature = "chrono")]
+    pub scheduled_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Identifier for the topic that this course work material is associated with.
+    pub topic_id: Option<TopicId>,
+}
+
+impl CourseWorkMaterialModify {
+    /// The `updateMask` for this modification: the API names of every field that is [`Some`], comma-separated.
+    #[must_use]
+    pub fn update_mask(&self) -> String {
+        crate::model::update_mask(&[
+            ("title", self.title.is_some()),
+            ("description", self.description.is_some()),
+            ("state", self.state.is_some()),
+            #[cfg(feature = "chrono")]
+            ("scheduledTime", self.scheduled_time.is_some()),
+            ("topicId", self.topic_id.is_some()),
+        ])
+    }
+}
+
+/// Possible states a course work material can be in.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(clippy::module_name_repetitions, clippy::enum_variant_names)]
+pub enum CourseWorkMaterialState {
+    /// No state specified. This is never returned.
+    #[serde(rename = "COURSEWORK_MATERIAL_STATE_UNSPECIFIED")]
+    CourseWorkMaterialStateUnspecified,
+    /// Status for course work material that has been published. This is the default state.
+    Published,
+    /// Status for a course work material that is not yet published. Course work material in this state is visible only to course teachers and domain administrators.
+    Draft,
+    /// Status for course work material that was published but is now deleted. Course work material in this state is visible only to course teachers and domain administrators. Course work material in this state is deleted after some time.
+    Deleted,
+}
+
+/// One page of course work materials, as returned by `courses.courseWorkMaterials.list`.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct ListCourseWorkMaterialResponse {
+    /// Course work material items that match the request.
+    #[serde(default)]
+    pub course_work_material: Vec<CourseWorkMaterial>,
+    /// Token identifying the next page of results to return. If empty, no further results are available.
+    pub next_page_token: Option<String>,
+}
+
+impl Page for ListCourseWorkMaterialResponse {
+    type Item = CourseWorkMaterial;
+
+    fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.course_work_material
+    }
+}
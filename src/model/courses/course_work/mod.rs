@@ -1,2 +1,306 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::{
+    courses::topics::TopicId, AssigneeMode, CourseWorkType, Date, DriveFolder, GradeCategory,
+    IndividualStudentsOptions, Material, Page, StudentGroupsOptions, TimeOfDay,
+};
+
 pub mod materials;
-pub mod submissions;
\ No newline at end of file
+pub mod rubrics;
+pub mod submissions;
+
+/// Course work created by a teacher for students of the course.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct CourseWork {
+    /// Identifier of the course.
+    pub course_id: String,
+    /// Classroom-assigned identifier of this course work, unique per course.
+    pub id: String,
+    /// Title of this course work. The title must be a valid UTF-8 string containing between 1 and 3000 characters.
+    pub title: String,
+    /// Optional description of this course work. If set, the description must be a valid UTF-8 string containing no more than 30,000 characters.
+    pub description: Option<String>,
+    /// Additional materials. Course work must have no more than 20 material items.
+    #[serde(default)]
+    pub materials: Vec<Material>,
+    /// Status of this course work. If unspecified, the default state is [`CourseWorkState::Draft`].
+    pub state: Option<CourseWorkState>,
+    /// Absolute link to this course work in the Classroom web UI. This is only populated if state is [`CourseWorkState::Published`].
+    pub alternate_link: Option<String>,
+    /// Timestamp when this course work was created.
+    #[cfg(feature = "chrono")]
+    pub creation_time: chrono::DateTime<chrono::Utc>,
+    /// Timestamp of the most recent change to this course work.
+    #[cfg(feature = "chrono")]
+    pub update_time: chrono::DateTime<chrono::Utc>,
+    /// Optional date, in UTC, that submissions for this course work are due. This must be specified if `due_time` is specified.
+    pub due_date: Option<Date>,
+    /// Optional time of day, in UTC, that submissions for this course work are due. This must be specified if `due_date` is specified.
+    pub due_time: Option<TimeOfDay>,
+    /// Optional timestamp when this course work is scheduled to be published.
+    #[cfg(feature = "chrono")]
+    pub scheduled_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Maximum grade for this course work. If zero or unspecified, this assignment is considered ungraded. This must be a non-negative integer value.
+    pub max_points: Option<f64>,
+    /// Type of this course work.
+    pub work_type: CourseWorkType,
+    /// Whether this course work item is associated with the Developer Console project making the request.
+    #[serde(default)]
+    pub associated_with_developer: bool,
+    /// Assignee mode of the course work. If unspecified, the default value is [`AssigneeMode::AllStudents`].
+    pub assignee_mode: Option<AssigneeMode>,
+    /// Identifiers of students with access to the course work. If the assignee mode is [`AssigneeMode::IndividiualStudents`], then only students specified in this field are assigned the course work.
+    pub individual_students_options: Option<IndividualStudentsOptions>,
+    /// Identifiers of student groups with access to the course work. If the assignee mode is [`AssigneeMode::StudentGroups`], then only members of these groups are assigned the course work.
+    pub student_groups_options: Option<StudentGroupsOptions>,
+    /// Setting to determine when students are allowed to modify submissions. If unspecified, the default value is [`SubmissionModificationMode::ModifiableUntilTurnedIn`].
+    pub submission_modification_mode: Option<SubmissionModificationMode>,
+    /// Identifier for the user that created the coursework.
+    pub creator_user_id: String,
+    /// Identifier for the topic that this coursework is associated with. Must match an existing topic in the course.
+    pub topic_id: Option<TopicId>,
+    /// The category that this coursework's grade contributes to. Present only when a category has been chosen for the coursework.
+    pub grade_category: Option<GradeCategory>,
+    /// Identifier of the grading period associated with the coursework. An empty string means it is associated with none.
+    pub grading_period_id: Option<String>,
+    /// Assignment details. This is populated only when `work_type` is [`CourseWorkType::Assignment`].
+    pub assignment: Option<Assignment>,
+    /// Multiple choice question details. For read operations, this field is populated only when `work_type` is [`CourseWorkType::MultipleChoiceQuestion`].
+    pub multiple_choice_question: Option<MultipleChoiceQuestion>,
+}
+
+/// Create course work.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct CourseWorkCreate {
+    /// Title of this course work. The title must be a valid UTF-8 string containing between 1 and 3000 characters.
+    pub title: String,
+    /// Optional description of this course work. If set, the description must be a valid UTF-8 string containing no more than 30,000 characters.
+    pub description: Option<String>,
+    /// Additional materials. Course work must have no more than 20 material items.
+    pub materials: Vec<Material>,
+    /// Status of this course work. If unspecified, the default state is [`CourseWorkState::Draft`].
+    pub state: Option<CourseWorkState>,
+    /// Optional date, in UTC, that submissions for this course work are due. This must be specified if `due_time` is specified.
+    pub due_date: Option<Date>,
+    /// Optional time of day, in UTC, that submissions for this course work are due. This must be specified if `due_date` is specified.
+    pub due_time: Option<TimeOfDay>,
+    /// Optional timestamp when this course work is scheduled to be published.
+    #[cfg(feature = "chrono")]
+    pub scheduled_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Maximum grade for this course work. If zero or unspecified, this assignment is considered ungraded. This must be a non-negative integer value.
+    pub max_points: Option<f64>,
+    /// Type of this course work.
+    pub work_type: CourseWorkType,
+    /// Assignee mode of the course work. If unspecified, the default value is [`AssigneeMode::AllStudents`].
+    pub assignee_mode: Option<AssigneeMode>,
+    /// Identifiers of students with access to the course work. Only used when the assignee mode is [`AssigneeMode::IndividiualStudents`].
+    pub individual_students_options: Option<IndividualStudentsOptions>,
+    /// Identifiers of student groups with access to the course work. Only used when the assignee mode is [`AssigneeMode::StudentGroups`].
+    pub student_groups_options: Option<StudentGroupsOptions>,
+    /// Setting to determine when students are allowed to modify submissions. If unspecified, the default value is [`SubmissionModificationMode::ModifiableUntilTurnedIn`].
+    pub submission_modification_mode: Option<SubmissionModificationMode>,
+    /// Identifier for the topic that this coursework is associated with. Must match an existing topic in the course.
+    pub topic_id: Option<TopicId>,
+    /// The category that this coursework's grade contributes to.
+    pub grade_category: Option<GradeCategory>,
+    /// Identifier of the grading period to associate the coursework with. If unset, it is chosen from `due_date`, or `scheduled_time` if there is no due date. Set to an empty string to associate it with none.
+    pub grading_period_id: Option<String>,
+    /// Multiple choice question details. Required when `work_type` is [`CourseWorkType::MultipleChoiceQuestion`].
+    pub multiple_choice_question: Option<MultipleChoiceQuestion>,
+}
+
+/// Modify course work.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct CourseWorkModify {
+    /// Title of this course work. The title must be a valid UTF-8 string containing between 1 and 3000 characters.
+    pub title: Option<String>,
+    /// Optional description of this course work. If set, the description must be a valid UTF-8 string containing no more than 30,000 characters.
+    pub description: Option<String>,
+    /// Status of this course work. Course work may only be moved from [`CourseWorkState::Draft`] to [`CourseWorkState::Published`].
+    pub state: Option<CourseWorkState>,
+    /// Optional date, in UTC, that submissions for this course work are due.
+    pub due_date: Option<Date>,
+    /// Optional time of day, in UTC, that submissions for this course work are due.
+    pub due_time: Option<TimeOfDay>,
+    /// Optional timestamp when this course work is scheduled to be published.
+    #[cfg(feature = "chrono")]
+    pub scheduled_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Maximum grade for this course work.
+    pub max_points: Option<f64>,
+    /// Setting to determine when students are allowed to modify submissions.
+    pub submission_modification_mode: Option<SubmissionModificationMode>,
+    /// Identifier for the topic that this coursework is associated with.
+    pub topic_id: Option<TopicId>,
+    /// The category that this coursework's grade contributes to.
+    pub grade_category: Option<GradeCategory>,
+    /// Identifier of the grading period to associate the coursework with, or an empty string for none.
+    pub grading_period_id: Option<String>,
+}
+
+impl CourseWorkModify {
+    /// The `updateMask` for this modification: the API names of every field that is [`Some`], comma-separated.
+    #[must_use]
+    pub fn update_mask(&self) -> String {
+        crate::model::update_mask(&[
+            ("title", self.title.is_some()),
+            ("description", self.description.is_some()),
+            ("state", self.state.is_some()),
+            ("dueDate", self.due_date.is_some()),
+            ("dueTime", self.due_time.is_some()),
+            #[cfg(feature = "chrono")]
+            ("scheduledTime", self.scheduled_time.is_some()),
+            ("maxPoints", self.max_points.is_some()),
+            (
+                "submissionModificationMode",
+                self.submission_modification_mode.is_some(),
+            ),
+            ("topicId", self.topic_id.is_some()),
+            ("gradeCategory", self.grade_category.is_some()),
+            ("gradingPeriodId", self.grading_period_id.is_some()),
+        ])
+    }
+}
+
+/// Possible states course work can be in.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(clippy::module_name_repetitions, clippy::enum_variant_names)]
+pub enum CourseWorkState {
+    /// No state specified. This is never returned.
+    CourseWorkStateUnspecified,
+    /// Status for work that has been published. This is the default state.
+    Published,
+    /// Status for work that is not yet published. Work in this state is visible only to course teachers and domain administrators.
+    Draft,
+    /// Status for work that was published but is now deleted. Work in this state is visible only to course teachers and domain administrators. Work in this state is deleted after some time.
+    Deleted,
+}
+
+/// Possible values for when students may modify their submissions.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(clippy::enum_variant_names)]
+pub enum SubmissionModificationMode {
+    /// No modification mode specified. This is never returned.
+    SubmissionModificationModeUnspecified,
+    /// Submissions can be modified before being turned in.
+    ModifiableUntilTurnedIn,
+    /// Submissions can be modified at any time.
+    Modifiable,
+}
+
+/// Additional details for assignments.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Assignment {
+    /// Drive folder where attachments from student submissions are placed. This is only populated for course teachers and administrators.
+    pub student_work_folder: Option<DriveFolder>,
+}
+
+/// Additional details for multiple-choice questions.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MultipleChoiceQuestion {
+    /// Possible choices.
+    pub choices: Vec<String>,
+}
+
+/// One page of course work, as returned by `courses.courseWork.list`.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct ListCourseWorkResponse {
+    /// Course work items that match the request.
+    #[serde(default)]
+    pub course_work: Vec<CourseWork>,
+    /// Token identifying the next page of results to return. If empty, no further results are available.
+    pub next_page_token: Option<String>,
+}
+
+impl Page for ListCourseWorkResponse {
+    type Item = CourseWork;
+
+    fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.course_work
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Link;
+
+    #[test]
+    fn decodes_course_work_with_grade_category() {
+        let course_work: CourseWork = serde_json::from_str(
+            r#"{
+                "courseId": "123456",
+                "id": "654321",
+                "title": "Cell structure worksheet",
+                "materials": [
+                    {
+                        "link": {
+                            "url": "https://example.com/cells",
+                            "title": "Cells",
+                            "thumbnailUrl": "https://example.com/cells.png"
+                        }
+                    }
+                ],
+                "state": "PUBLISHED",
+                "alternateLink": "https://classroom.google.com/c/MTIzNDU2/a/NjU0MzIx/details",
+                "creationTime": "2024-09-01T08:00:00.456Z",
+                "updateTime": "2024-09-01T08:05:00Z",
+                "dueDate": { "year": 2024, "month": 9, "day": 8 },
+                "dueTime": { "hours": 23, "minutes": 59 },
+                "maxPoints": 10,
+                "workType": "ASSIGNMENT",
+                "assigneeMode": "ALL_STUDENTS",
+                "submissionModificationMode": "MODIFIABLE_UNTIL_TURNED_IN",
+                "creatorUserId": "987654",
+                "gradeCategory": {
+                    "id": "1",
+                    "name": "Homework",
+                    "weight": 500000,
+                    "defaultGradeDenominator": 10
+                },
+                "assignment": {}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(course_work.state, Some(CourseWorkState::Published));
+        assert_eq!(course_work.max_points, Some(10.0));
+        assert_eq!(
+            course_work.materials,
+            [Material::Link(Link {
+                url: "https://example.com/cells".to_string(),
+                title: Some("Cells".to_string()),
+                thumbnail_url: Some("https://example.com/cells.png".to_string()),
+            })]
+        );
+        assert_eq!(
+            course_work.grade_category,
+            Some(GradeCategory {
+                id: "1".to_string(),
+                name: "Homework".to_string(),
+                weight: Some(500_000),
+                default_grade_denominator: Some(10),
+            })
+        );
+        assert_eq!(
+            course_work.assignment,
+            Some(Assignment {
+                student_work_folder: None
+            })
+        );
+    }
+}
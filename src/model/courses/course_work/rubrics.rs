@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::Page;
+
+/// The rubric of a course work, a set of criteria used to grade student submissions.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Rubric {
+    /// Identifier of the course.
+    pub course_id: String,
+    /// Identifier for the course work this corresponds to.
+    pub course_work_id: String,
+    /// Classroom-assigned identifier for the rubric. This is unique among rubrics for the relevant course work.
+    pub id: String,
+    /// The time the rubric was created.
+    #[cfg(feature = "chrono")]
+    pub creation_time: chrono::DateTime<chrono::Utc>,
+    /// The time the rubric was last updated.
+    #[cfg(feature = "chrono")]
+    pub update_time: chrono::DateTime<chrono::Utc>,
+    /// List of criteria. Each criterion is a dimension on which performance is rated.
+    #[serde(default)]
+    pub criteria: Vec<Criterion>,
+}
+
+impl Rubric {
+    /// A copy of this rubric which can be created on other course work, with every Classroom-assigned identifier cleared.
+    #[must_use]
+    pub fn to_create(&self) -> RubricCreate {
+        RubricCreate {
+            criteria: self.criteria.iter().map(Criterion::to_create).collect(),
+            source_spreadsheet_id: None,
+        }
+    }
+}
+
+/// A rubric criterion. Each criterion is a dimension on which performance is rated.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Criterion {
+    /// The criterion ID. On creation, an ID is assigned.
+    pub id: Option<String>,
+    /// The title of the criterion.
+    pub title: String,
+    /// The description of the criterion.
+    pub description: Option<String>,
+    /// The list of levels within this criterion.
+    #[serde(default)]
+    pub levels: Vec<Level>,
+}
+
+impl Criterion {
+    /// A copy of this criterion with its own identifier and those of its levels cleared.
+    #[must_use]
+    pub fn to_create(&self) -> Self {
+        Self {
+            id: None,
+            title: self.title.clone(),
+            description: self.description.clone(),
+            levels: self
+                .levels
+                .iter()
+                .map(|level| Level {
+                    id: None,
+                    ..level.clone()
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A level of the criterion.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Level {
+    /// The level ID. On creation, an ID is assigned.
+    pub id: Option<String>,
+    /// The title of the level. If the level has no points set, title must be set.
+    pub title: Option<String>,
+    /// The description of the level.
+    pub description: Option<String>,
+    /// Optional points associated with this level. If set, all levels within the rubric must specify points and the value must be distinct across all levels within a single criterion. 0 is distinct from no points.
+    pub points: Option<f64>,
+}
+
+/// Create a rubric. Each course work may have at most one rubric.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct RubricCreate {
+    /// List of criteria. Each criterion is a dimension on which performance is rated.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub criteria: Vec<Criterion>,
+    /// Input only. Immutable. Google Sheets ID of the spreadsheet. This spreadsheet must contain formatted rubric settings. Set instead of `criteria`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_spreadsheet_id: Option<String>,
+}
+
+/// Modify a rubric.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct RubricModify {
+    /// Replacement list of criteria. Criteria and levels keep their identifiers where they are set, and are created where they are not.
+    pub criteria: Option<Vec<Criterion>>,
+}
+
+impl RubricModify {
+    /// The `updateMask` for this modification: the API names of every field that is [`Some`], comma-separated.
+    #[must_use]
+    pub fn update_mask(&self) -> String {
+        crate::model::update_mask(&[("criteria", self.criteria.is_some())])
+    }
+}
+
+/// A rubric-based grade for one criterion of a [`StudentSubmission`](super::submissions::StudentSubmission).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RubricGrade {
+    /// Criterion ID.
+    pub criterion_id: String,
+    /// Optional level ID of the selected level. If empty, no level was selected.
+    pub level_id: Option<String>,
+    /// Optional points assigned for this criterion, typically based on the level. Levels might or might not have points. If unset, no points were set for this criterion.
+    pub points: Option<f64>,
+}
+
+/// One page of rubrics, as returned by `courses.courseWork.rubrics.list`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct ListRubricsResponse {
+    /// Rubrics that match the request.
+    #[serde(default)]
+    pub rubrics: Vec<Rubric>,
+    /// Token identifying the next page of results to return. If empty, no further results are available.
+    pub next_page_token: Option<String>,
+}
+
+impl Page for ListRubricsResponse {
+    type Item = Rubric;
+
+    fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.rubrics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn omits_criteria_when_creating_from_spreadsheet() {
+        let create = RubricCreate {
+            criteria: Vec::new(),
+            source_spreadsheet_id: Some("1abc".to_string()),
+        };
+        assert_eq!(
+            serde_json::to_value(&create).unwrap(),
+            serde_json::json!({ "sourceSpreadsheetId": "1abc" })
+        );
+    }
+}
@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::rubrics::RubricGrade;
+use crate::model::{CourseWorkType, DriveFile, Form, Link, Page, YouTubeVideo};
+
+/// Student submission for course work.
+///
+/// Submissions are created by Classroom when course work is created, and only exist for students assigned the work.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct StudentSubmission {
+    /// Identifier of the course.
+    pub course_id: String,
+    /// Identifier for the course work this corresponds to.
+    pub course_work_id: String,
+    /// Classroom-assigned identifier for the student submission. This is unique among submissions for the relevant course work.
+    pub id: String,
+    /// Identifier for the student that owns this submission.
+    pub user_id: String,
+    /// Creation time of this submission. This may be unset if the student has not accessed this item.
+    #[cfg(feature = "chrono")]
+    pub creation_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Last update time of this submission. This may be unset if the student has not accessed this item.
+    #[cfg(feature = "chrono")]
+    pub update_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// State of this submission.
+    pub state: SubmissionState,
+    /// Whether this submission is late.
+    #[serde(default)]
+    pub late: bool,
+    /// Optional pending grade. If unset, no grade was set. This value must be non-negative. Decimal (that is, non-integer) values are allowed, but are rounded to two decimal places. This is only visible to and modifiable by course teachers.
+    pub draft_grade: Option<f64>,
+    /// Optional grade. If unset, no grade was set. This value must be non-negative. Decimal (that is, non-integer) values are allowed, but are rounded to two decimal places.
+    pub assigned_grade: Option<f64>,
+    /// Pending rubric grades based on the rubric's criteria, keyed by criterion ID. This is only visible to and modifiable by course teachers.
+    #[serde(default)]
+    pub draft_rubric_grades: HashMap<String, RubricGrade>,
+    /// Assigned rubric grades based on the rubric's criteria, keyed by criterion ID.
+    #[serde(default)]
+    pub assigned_rubric_grades: HashMap<String, RubricGrade>,
+    /// Absolute link to the submission in the Classroom web UI.
+    pub alternate_link: String,
+    /// Type of course work this submission is for.
+    pub course_work_type: CourseWorkType,
+    /// Whether this student submission is associated with the Developer Console project making the request.
+    #[serde(default)]
+    pub associated_with_developer: bool,
+    /// The history of the submission, in chronological order.
+    #[serde(default)]
+    pub submission_history: Vec<SubmissionHistory>,
+    /// The student's work, which depends on the type of course work. Unset if the student has not started on it.
+    #[serde(flatten)]
+    pub content: Option<SubmissionContent>,
+}
+
+/// Modify a student submission.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct StudentSubmissionModify {
+    /// Pending grade, only visible to course teachers.
+    pub draft_grade: Option<f64>,
+    /// Grade returned to the student.
+    pub assigned_grade: Option<f64>,
+    /// Pending rubric grades, keyed by criterion ID, only visible to course teachers.
+    pub draft_rubric_grades: Option<HashMap<String, RubricGrade>>,
+    /// Rubric grades returned to the student, keyed by criterion ID.
+    pub assigned_rubric_grades: Option<HashMap<String, RubricGrade>>,
+}
+
+impl StudentSubmissionModify {
+    /// The `updateMask` for this modification: the API names of every field that is [`Some`], comma-separated.
+    #[must_use]
+    pub fn update_mask(&self) -> String {
+        crate::model::update_mask(&[
+            ("draftGrade", self.draft_grade.is_some()),
+            ("assignedGrade", self.assigned_grade.is_some()),
+            ("draftRubricGrades", self.draft_rubric_grades.is_some()),
+            (
+                "assignedRubricGrades",
+                self.assigned_rubric_grades.is_some(),
+            ),
+        ])
+    }
+}
+
+/// The student's work in a [`StudentSubmission`].
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum SubmissionContent {
+    /// Submission content when the course work type is [`CourseWorkType::Assignment`].
+    AssignmentSubmission(AssignmentSubmission),
+    /// Submission content when the course work type is [`CourseWorkType::ShortAnswerQuestion`].
+    ShortAnswerSubmission(ShortAnswerSubmission),
+    /// Submission content when the course work type is [`CourseWorkType::MultipleChoiceQuestion`].
+    MultipleChoiceSubmission(MultipleChoiceSubmission),
+}
+
+/// Student work for an assignment.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AssignmentSubmission {
+    /// Attachments added by the student. Drive files that correspond to materials with a share mode of ``STUDENT_COPY`` may not exist yet if the student has not accessed the assignment in Classroom.
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+}
+
+/// Student work for a short answer question.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ShortAnswerSubmission {
+    /// Student response to a short-answer question.
+    pub answer: String,
+}
+
+/// Student work for a multiple-choice question.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MultipleChoiceSubmission {
+    /// Student's select choice.
+    pub answer: String,
+}
+
+/// Attachment added to student assignment work.
+///
+/// When creating attachments, setting the form field is not supported.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum Attachment {
+    DriveFile(DriveFile),
+    #[serde(rename = "youTubeVideo")]
+    YouTubeVideo(YouTubeVideo),
+    Link(Link),
+    Form(Form),
+}
+
+/// Request body for `studentSubmissions.modifyAttachments`.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifyAttachments {
+    /// Attachments to add. A student submission may not have more than 20 attachments.
+    pub add_attachments: Vec<Attachment>,
+}
+
+/// Possible states a submission can be in.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(clippy::module_name_repetitions, clippy::enum_variant_names)]
+pub enum SubmissionState {
+    /// No state specified. This should never be returned.
+    SubmissionStateUnspecified,
+    /// The student has never accessed this submission. Attachments are not returned and timestamps is not set.
+    New,
+    /// Has been created.
+    Created,
+    /// Has been turned in to the teacher.
+    TurnedIn,
+    /// Has been returned to the student.
+    Returned,
+    /// Student chose to "unsubmit" the assignment.
+    ReclaimedByStudent,
+}
+
+/// Filter for whether submissions are late.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(clippy::enum_variant_names)]
+pub enum LateValues {
+    /// No restriction on submission late values specified.
+    LateValuesUnspecified,
+    /// Return student submissions where late is true.
+    LateOnly,
+    /// Return student submissions where late is false.
+    NotLateOnly,
+}
+
+/// The history of a submission.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum SubmissionHistory {
+    /// The state history information of the submission, if present.
+    StateHistory(StateHistory),
+    /// The grade history information of the submission, if present.
+    GradeHistory(GradeHistory),
+}
+
+/// The history of each state this submission has been in.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StateHistory {
+    /// The workflow pipeline stage.
+    pub state: StateHistoryState,
+    /// When the submission entered this state.
+    #[cfg(feature = "chrono")]
+    pub state_timestamp: chrono::DateTime<chrono::Utc>,
+    /// The teacher or student who made the change.
+    pub actor_user_id: String,
+}
+
+/// Possible states in a [`StateHistory`].
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(clippy::enum_variant_names)]
+pub enum StateHistoryState {
+    /// No state specified. This should never be returned.
+    StateUnspecified,
+    /// The Submission has been created.
+    Created,
+    /// The student has turned in an assigned document, which may or may not be a template.
+    TurnedIn,
+    /// The teacher has returned the assigned document to the student.
+    Returned,
+    /// The student turned in the assigned document, and then chose to "unsubmit" the assignment, giving the student control again as the owner.
+    ReclaimedByStudent,
+    /// The student edited their submission after turning it in. Currently, only used by Questions, when the student edits their answer.
+    StudentEditedAfterTurnIn,
+}
+
+/// The history of each grade on this submission.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GradeHistory {
+    /// The numerator of the grade at this time in the submission grade history.
+    pub points_earned: Option<f64>,
+    /// The denominator of the grade at this time in the submission grade history.
+    pub max_points: Option<f64>,
+    /// When the grade of the submission was changed.
+    #[cfg(feature = "chrono")]
+    pub grade_timestamp: chrono::DateTime<chrono::Utc>,
+    /// The teacher who made the grade change.
+    pub actor_user_id: String,
+    /// The type of grade change at this time in the submission grade history.
+    pub grade_change_type: GradeChangeType,
+}
+
+/// Possible types of grade change in a [`GradeHistory`].
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(clippy::enum_variant_names)]
+pub enum GradeChangeType {
+    /// No grade change type specified. This should never be returned.
+    UnknownGradeChangeType,
+    /// A change in the numerator of the draft grade.
+    DraftGradePointsEarnedChange,
+    /// A change in the numerator of the assigned grade.
+    AssignedGradePointsEarnedChange,
+    /// A change in the denominator of the grade.
+    MaxPointsChange,
+}
+
+/// One page of student submissions, as returned by `courses.courseWork.studentSubmissions.list`.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct ListStudentSubmissionsResponse {
+    /// Student work that matches the request.
+    #[serde(default)]
+    pub student_submissions: Vec<StudentSubmission>,
+    /// Token identifying the next page of results to return. If empty, no further results are available.
+    pub next_page_token: Option<String>,
+}
+
+impl Page for ListStudentSubmissionsResponse {
+    type Item = StudentSubmission;
+
+    fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.student_submissions
+    }
+}
@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::Date;
+
+/// The grading period settings of a course.
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct GradingPeriodSettings {
+    /// The list of grading periods in a specific course. Grading periods must not have overlapping date ranges and must be listed in chronological order.
+    #[serde(default)]
+    pub grading_periods: Vec<GradingPeriod>,
+    /// Supports toggling the application of grading periods on existing stream items. Once set, this value is persisted meaning that it does not need to be set in every request to update the settings.
+    #[serde(default)]
+    pub apply_to_existing_coursework: bool,
+}
+
+impl GradingPeriodSettings {
+    /// The grading period which `date` falls in, if any. Both ends of a period are inclusive.
+    #[must_use]
+    pub fn period_for(&self, date: Date) -> Option<&GradingPeriod> {
+        self.grading_periods
+            .iter()
+            .find(|period| period.contains(date))
+    }
+}
+
+/// An individual grading period. Grading periods must not have overlapping date ranges.
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct GradingPeriod {
+    /// A unique identifier for the grading period. Assigned by Classroom; leave unset when adding a period, and keep it set when updating an existing one.
+    pub id: Option<String>,
+    /// Title of the grading period. For example, "Semester 1".
+    pub title: String,
+    /// Start date, in UTC, of the grading period. Inclusive.
+    pub start_date: Date,
+    /// End date, in UTC, of the grading period. Inclusive.
+    pub end_date: Date,
+}
+
+impl GradingPeriod {
+    /// Whether `date` is between the start and end of this period, inclusive.
+    #[must_use]
+    pub fn contains(&self, date: Date) -> bool {
+        (self.start_date..=self.end_date).contains(&date)
+    }
+}
+
+/// Modify the grading period settings of a course.
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct GradingPeriodSettingsModify {
+    /// Replacement list of grading periods. Periods without an `id` are added, and existing periods missing from the list are removed.
+    pub grading_periods: Option<Vec<GradingPeriod>>,
+    /// Whether to apply the grading periods to existing course work.
+    pub apply_to_existing_coursework: Option<bool>,
+}
+
+impl GradingPeriodSettingsModify {
+    /// The `updateMask` for this modification: the API names of every field that is [`Some`], comma-separated.
+    #[must_use]
+    pub fn update_mask(&self) -> String {
+        crate::model::update_mask(&[
+            ("gradingPeriods", self.grading_periods.is_some()),
+            (
+                "applyToExistingCoursework",
+                self.apply_to_existing_coursework.is_some(),
+            ),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn date(year: i32, month: u32, day: u32) -> Date {
+        Date { year, month, day }
+    }
+
+    fn settings() -> GradingPeriodSettings {
+        let period = |title: &str, start_date, end_date| GradingPeriod {
+            id: None,
+            title: title.to_string(),
+            start_date,
+            end_date,
+        };
+        GradingPeriodSettings {
+            grading_periods: vec![
+                period("Semester 1", date(2024, 9, 2), date(2024, 12, 20)),
+                period("Semester 2", date(2025, 1, 6), date(2025, 6, 13)),
+            ],
+            apply_to_existing_coursework: false,
+        }
+    }
+
+    fn title(settings: &GradingPeriodSettings, date: Date) -> Option<&str> {
+        settings
+            .period_for(date)
+            .map(|period| period.title.as_str())
+    }
+
+    #[test]
+    fn includes_first_and_last_day() {
+        let settings = settings();
+        assert_eq!(title(&settings, date(2024, 9, 2)), Some("Semester 1"));
+        assert_eq!(title(&settings, date(2024, 12, 20)), Some("Semester 1"));
+        assert_eq!(title(&settings, date(2025, 1, 6)), Some("Semester 2"));
+        assert_eq!(title(&settings, date(2025, 6, 13)), Some("Semester 2"));
+    }
+
+    #[test]
+    fn finds_period_containing_date() {
+        assert_eq!(title(&settings(), date(2025, 3, 31)), Some("Semester 2"));
+    }
+
+    #[test]
+    fn finds_nothing_between_periods() {
+        assert_eq!(title(&settings(), date(2024, 12, 21)), None);
+        assert_eq!(title(&settings(), date(2025, 1, 5)), None);
+    }
+
+    #[test]
+    fn finds_nothing_outside_all_periods() {
+        assert_eq!(title(&settings(), date(2024, 9, 1)), None);
+        assert_eq!(title(&settings(), date(2025, 6, 14)), None);
+        assert_eq!(title(&settings(), date(2026, 1, 1)), None);
+    }
+}
@@ -1,10 +1,14 @@
-use serde::{de::Visitor, Deserialize, Serialize};
+use serde::{Deserialize, Serialize};
 
-use super::{DriveFolder, GradeCategory};
+use self::aliases::AliasScope;
+use super::{user_profiles::UserId, DriveFolder, GradeCategory, Page};
 
+pub mod add_ons;
 pub mod aliases;
 pub mod announcements;
 pub mod course_work;
+pub mod grading_periods;
+pub mod student_groups;
 pub mod students;
 pub mod teachers;
 pub mod topics;
@@ -14,8 +18,8 @@ pub mod topics;
 #[serde(rename_all = "camelCase")]
 #[allow(clippy::module_name_repetitions)]
 pub struct CourseCreate {
-    /// When creating a course, you may optionally set this identifier to an alias string in the request to create a corresponding alias. The id is still assigned by Classroom and cannot be updated after the course is created.
-    pub id: Option<String>,
+    /// When creating a course, you may optionally set this identifier to an alias in the request to create a corresponding alias. The id is still assigned by Classroom and cannot be updated after the course is created.
+    pub id: Option<AliasScope>,
     /// Name of the course. For example, "10th Grade Biology". The name is required. It must be between 1 and 750 characters and a valid UTF-8 string.
     pub name: String,
     /// Section of the course. For example, "Period 2". If set, this field must be a valid UTF-8 string and no longer than 2800 characters.
@@ -53,6 +57,22 @@ pub struct CourseModify {
     pub course_state: Option<CourseState>,
 }
 
+impl CourseModify {
+    /// The `updateMask` for this modification: the API names of every field that is [`Some`], comma-separated.
+    #[must_use]
+    pub fn update_mask(&self) -> String {
+        crate::model::update_mask(&[
+            ("name", self.name.is_some()),
+            ("section", self.section.is_some()),
+            ("descriptionHeading", self.description_heading.is_some()),
+            ("description", self.description.is_some()),
+            ("room", self.room.is_some()),
+            ("ownerId", self.owner_id.is_some()),
+            ("courseState", self.course_state.is_some()),
+        ])
+    }
+}
+
 /// A Course in Classroom.
 #[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -78,26 +98,51 @@ pub struct Course {
     #[cfg(feature = "chrono")]
     pub update_time: chrono::DateTime<chrono::Utc>,
     /// Enrollment code to use when joining this course. Specifying this field in a course update mask results in an error.
-    pub enrollment_code: String,
+    pub enrollment_code: Option<String>,
     /// State of the course. If unspecified, the default state is [`CourseState::Provisioned`].
     pub course_state: Option<CourseState>,
     /// Absolute link to this course in the Classroom web UI.
     pub alternate_link: String,
     /// The email address of a Google group containing all teachers of the course. This group does not accept email and can only be used for permissions.
-    pub teacher_group_email: String,
+    pub teacher_group_email: Option<String>,
     /// The email address of a Google group containing all members of the course. This group does not accept email and can only be used for permissions.
-    pub course_group_email: String,
+    pub course_group_email: Option<String>,
     /// Information about a Drive Folder that is shared with all teachers of the course.
     /// This field will only be set for teachers of the course and domain administrators.
     pub teacher_folder: Option<DriveFolder>,
     /// Whether or not guardian notifications are enabled for this course.
+    #[serde(default)]
     pub guardians_enabled: bool,
     /// The Calendar ID for a calendar that all course members can see, to which Classroom adds events for course work and announcements in the course.
-    pub calendar_id: String,
+    pub calendar_id: Option<String>,
     /// The gradebook settings that specify how a student's overall grade for the course will be calculated and who it will be displayed to.
     pub gradebook_settings: GradebookSettings,
 }
 
+/// One page of courses, as returned by `courses.list`.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct ListCoursesResponse {
+    /// Courses that match the list request.
+    #[serde(default)]
+    pub courses: Vec<Course>,
+    /// Token identifying the next page of results to return. If empty, no further results are available.
+    pub next_page_token: Option<String>,
+}
+
+impl Page for ListCoursesResponse {
+    type Item = Course;
+
+    fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.courses
+    }
+}
+
 /// Possible states a course can be in.
 #[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -117,6 +162,7 @@ pub enum CourseState {
 pub struct GradebookSettings {
     calculation_type: CalculationType,
     display_setting: DisplaySetting,
+    #[serde(default)]
     grade_categories: Vec<GradeCategory>,
 }
 
@@ -141,72 +187,92 @@ pub enum DisplaySetting {
     ShowTeachersOnly,
 }
 
-/// This is a simple wrapper type, no guarentees are made.
-#[derive(Debug, Hash, PartialEq, Eq)]
-pub enum OwnerId {
-    /// Email (unchecked)
-    Email(String),
-    /// Unique numeric ID (numeric only checked in deserialization)
-    Id(String),
-    /// Specifies the current user
-    Me,
-}
-
-impl Serialize for OwnerId {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        let value = match self {
-            Self::Email(email) => email,
-            Self::Id(id) => id,
-            Self::Me => "me",
-        };
-        serializer.serialize_str(value)
-    }
-}
-
-struct OwnerIdVisitor;
-
-impl<'v> Visitor<'v> for OwnerIdVisitor {
-    type Value = OwnerId;
-
-    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        formatter.write_str("Expected `str`")
-    }
+/// The identifier of a course owner, see [`UserId`].
+pub type OwnerId = UserId;
 
-    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
-    where
-        E: serde::de::Error,
-    {
-        if v == "me" {
-            return Ok(OwnerId::Me);
-        };
-        if v.chars().all(|c| c.is_ascii_digit()) {
-            return Ok(OwnerId::Id(v));
-        }
-        Ok(OwnerId::Email(v))
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
-    where
-        E: serde::de::Error,
-    {
-        if v == "me" {
-            return Ok(OwnerId::Me);
-        };
-        if v.chars().all(|c| c.is_ascii_digit()) {
-            return Ok(OwnerId::Id(v.to_string()));
-        }
-        Ok(OwnerId::Email(v.to_string()))
+    #[test]
+    fn decodes_course_with_grade_categories() {
+        let course: Course = serde_json::from_str(
+            r#"{
+                "id": "123456",
+                "name": "10th Grade Biology",
+                "section": "Period 2",
+                "ownerId": "987654",
+                "creationTime": "2024-08-01T09:30:00.123Z",
+                "updateTime": "2024-08-02T10:00:00Z",
+                "enrollmentCode": "abc123",
+                "courseState": "ACTIVE",
+                "alternateLink": "https://classroom.google.com/c/MTIzNDU2",
+                "teacherGroupEmail": "biology_teachers@example.com",
+                "courseGroupEmail": "biology@example.com",
+                "teacherFolder": {
+                    "id": "folder",
+                    "title": "10th Grade Biology",
+                    "alternateLink": "https://drive.google.com/drive/folders/folder"
+                },
+                "guardiansEnabled": true,
+                "calendarId": "classroom123@group.calendar.google.com",
+                "gradebookSettings": {
+                    "calculationType": "WEIGHTED_CATEGORIES",
+                    "displaySetting": "SHOW_OVERALL_GRADE",
+                    "gradeCategories": [
+                        { "id": "1", "name": "Homework", "weight": 500000, "defaultGradeDenominator": 10 },
+                        { "id": "2", "name": "Tests", "weight": 500000 }
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(course.id, "123456");
+        assert_eq!(course.course_state, Some(CourseState::Active));
+        let settings = &course.gradebook_settings;
+        assert_eq!(
+            settings.calculation_type,
+            CalculationType::WeightedCategories
+        );
+        assert_eq!(
+            settings.grade_categories,
+            [
+                GradeCategory {
+                    id: "1".to_string(),
+                    name: "Homework".to_string(),
+                    weight: Some(500_000),
+                    default_grade_denominator: Some(10),
+                },
+                GradeCategory {
+                    id: "2".to_string(),
+                    name: "Tests".to_string(),
+                    weight: Some(500_000),
+                    default_grade_denominator: None,
+                },
+            ]
+        );
     }
-}
 
-impl<'de> Deserialize<'de> for OwnerId {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        deserializer.deserialize_string(OwnerIdVisitor)
+    #[test]
+    fn decodes_course_without_optional_fields() {
+        let course: Course = serde_json::from_str(
+            r#"{
+                "id": "123456",
+                "name": "Biology",
+                "ownerId": "987654",
+                "creationTime": "2024-08-01T09:30:00Z",
+                "updateTime": "2024-08-01T09:30:00Z",
+                "courseState": "PROVISIONED",
+                "alternateLink": "https://classroom.google.com/c/MTIzNDU2",
+                "gradebookSettings": {
+                    "calculationType": "TOTAL_POINTS",
+                    "displaySetting": "HIDE_OVERALL_GRADE"
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(course.enrollment_code, None);
+        assert!(!course.guardians_enabled);
+        assert!(course.gradebook_settings.grade_categories.is_empty());
     }
 }
@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+
+use crate::model::{user_profiles::UserId, Page};
+
+/// A student group in a course.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StudentGroup {
+    /// The identifier of the course.
+    pub course_id: String,
+    /// The identifier of the student group.
+    pub id: String,
+    /// The title of the student group.
+    pub title: String,
+}
+
+/// Create a student group.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct StudentGroupCreate {
+    /// The title of the student group.
+    pub title: String,
+}
+
+/// Modify a student group.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct StudentGroupModify {
+    /// The title of the student group.
+    pub title: Option<String>,
+}
+
+impl StudentGroupModify {
+    /// The `updateMask` for this modification: the API names of every field that is [`Some`], comma-separated.
+    #[must_use]
+    pub fn update_mask(&self) -> String {
+        crate::model::update_mask(&[("title", self.title.is_some())])
+    }
+}
+
+/// A student in a student group.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StudentGroupMember {
+    /// The identifier of the course.
+    pub course_id: String,
+    /// The identifier of the student group.
+    pub student_group_id: String,
+    /// Identifier of the student.
+    pub user_id: String,
+}
+
+/// Add a student to a student group.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct StudentGroupMemberCreate {
+    /// The student to add, by numeric identifier or email address. They must already be a student in the course.
+    pub user_id: UserId,
+}
+
+/// One page of student groups, as returned by `courses.studentGroups.list`.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct ListStudentGroupsResponse {
+    /// The student groups.
+    #[serde(default)]
+    pub student_groups: Vec<StudentGroup>,
+    /// Token identifying the next page of results to return. If empty, no further results are available.
+    pub next_page_token: Option<String>,
+}
+
+impl Page for ListStudentGroupsResponse {
+    type Item = StudentGroup;
+
+    fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.student_groups
+    }
+}
+
+/// One page of student group members, as returned by `courses.studentGroups.studentGroupMembers.list`.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct ListStudentGroupMembersResponse {
+    /// The student group members.
+    #[serde(default)]
+    pub student_group_members: Vec<StudentGroupMember>,
+    /// Token identifying the next page of results to return. If empty, no further results are available.
+    pub next_page_token: Option<String>,
+}
+
+impl Page for ListStudentGroupMembersResponse {
+    type Item = StudentGroupMember;
+
+    fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.student_group_members
+    }
+}
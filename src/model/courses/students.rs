@@ -1 +1,53 @@
+use serde::{Deserialize, Serialize};
 
+use crate::model::{
+    user_profiles::{UserId, UserProfile},
+    DriveFolder, Page,
+};
+
+/// Student in a course.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Student {
+    /// Identifier of the course.
+    pub course_id: String,
+    /// Identifier of the user.
+    pub user_id: String,
+    /// Global user information for the student.
+    pub profile: UserProfile,
+    /// Information about a Drive Folder for this student's work in this course. Only visible to the student and domain administrators.
+    pub student_work_folder: Option<DriveFolder>,
+}
+
+/// Add a student to a course.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct StudentCreate {
+    /// The user to add, by numeric identifier, email address, or [`UserId::Me`].
+    pub user_id: UserId,
+}
+
+/// One page of students, as returned by `courses.students.list`.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct ListStudentsResponse {
+    /// Students who match the list request.
+    #[serde(default)]
+    pub students: Vec<Student>,
+    /// Token identifying the next page of results to return. If empty, no further results are available.
+    pub next_page_token: Option<String>,
+}
+
+impl Page for ListStudentsResponse {
+    type Item = Student;
+
+    fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.students
+    }
+}
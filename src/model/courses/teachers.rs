@@ -1 +1,51 @@
+use serde::{Deserialize, Serialize};
 
+use crate::model::{
+    user_profiles::{UserId, UserProfile},
+    Page,
+};
+
+/// Teacher of a course.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Teacher {
+    /// Identifier of the course.
+    pub course_id: String,
+    /// Identifier of the user.
+    pub user_id: String,
+    /// Global user information for the teacher.
+    pub profile: UserProfile,
+}
+
+/// Add a teacher to a course.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct TeacherCreate {
+    /// The user to add, by numeric identifier, email address, or [`UserId::Me`].
+    pub user_id: UserId,
+}
+
+/// One page of teachers, as returned by `courses.teachers.list`.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct ListTeachersResponse {
+    /// Teachers who match the list request.
+    #[serde(default)]
+    pub teachers: Vec<Teacher>,
+    /// Token identifying the next page of results to return. If empty, no further results are available.
+    pub next_page_token: Option<String>,
+}
+
+impl Page for ListTeachersResponse {
+    type Item = Teacher;
+
+    fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.teachers
+    }
+}
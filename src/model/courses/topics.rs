@@ -1 +1,102 @@
+use std::fmt;
 
+use serde::{Deserialize, Serialize};
+
+use crate::model::Page;
+
+/// Topic created by a teacher for the course.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Topic {
+    /// Identifier of the course.
+    pub course_id: String,
+    /// Unique identifier for the topic.
+    pub topic_id: TopicId,
+    /// The name of the topic, generated by the user. Leading and trailing whitespaces, if any, are trimmed. Also, multiple consecutive whitespaces are collapsed into one inside the name. The result must be a non-empty string. Topic names are case sensitive, and must be no longer than 100 characters.
+    pub name: String,
+    /// The time the topic was last updated by the system.
+    #[cfg(feature = "chrono")]
+    pub update_time: chrono::DateTime<chrono::Utc>,
+}
+
+/// Identifier of a [`Topic`], as referenced by course work and course work materials.
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(transparent)]
+#[allow(clippy::module_name_repetitions)]
+pub struct TopicId(pub String);
+
+impl TopicId {
+    /// The identifier as sent to the API.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for TopicId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<String> for TopicId {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for TopicId {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+/// Create a topic.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct TopicCreate {
+    /// The name of the topic. Topic names are case sensitive, and must be no longer than 100 characters.
+    pub name: String,
+}
+
+/// Modify a topic.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct TopicModify {
+    /// The name of the topic. Topic names are case sensitive, and must be no longer than 100 characters.
+    pub name: Option<String>,
+}
+
+impl TopicModify {
+    /// The `updateMask` for this modification: the API names of every field that is [`Some`], comma-separated.
+    #[must_use]
+    pub fn update_mask(&self) -> String {
+        crate::model::update_mask(&[("name", self.name.is_some())])
+    }
+}
+
+/// One page of topics, as returned by `courses.topics.list`.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct ListTopicResponse {
+    /// Topic items that match the request.
+    #[serde(default)]
+    pub topic: Vec<Topic>,
+    /// Token identifying the next page of results to return. If empty, no further results are available.
+    pub next_page_token: Option<String>,
+}
+
+impl Page for ListTopicResponse {
+    type Item = Topic;
+
+    fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.topic
+    }
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+use super::{courses::aliases::AliasScope, user_profiles::UserId, Page};
+
+/// An invitation to join a course.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Invitation {
+    /// Identifier assigned by Classroom.
+    pub id: String,
+    /// Identifier of the invited user.
+    pub user_id: String,
+    /// Identifier of the course to invite the user to.
+    pub course_id: String,
+    /// Role to invite the user to have.
+    pub role: CourseRole,
+}
+
+/// Create an invitation.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct InvitationCreate {
+    /// The user to invite, by numeric identifier or email address.
+    pub user_id: UserId,
+    /// The course to invite the user to, by identifier or alias.
+    pub course_id: AliasScope,
+    /// Role to invite the user to have. Must not be [`CourseRole::CourseRoleUnspecified`].
+    pub role: CourseRole,
+}
+
+/// Possible roles a user can have in a course.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(clippy::enum_variant_names)]
+pub enum CourseRole {
+    /// No course role.
+    CourseRoleUnspecified,
+    /// Student in the course.
+    Student,
+    /// Teacher of the course.
+    Teacher,
+    /// Owner of the course.
+    Owner,
+}
+
+/// One page of invitations, as returned by `invitations.list`.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct ListInvitationsResponse {
+    /// Invitations that match the list request.
+    #[serde(default)]
+    pub invitations: Vec<Invitation>,
+    /// Token identifying the next page of results to return. If empty, no further results are available.
+    pub next_page_token: Option<String>,
+}
+
+impl Page for ListInvitationsResponse {
+    type Item = Invitation;
+
+    fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.invitations
+    }
+}
@@ -4,6 +4,34 @@ pub mod user_profiles;
 pub mod invitations;
 pub mod registrations;
 
+/// Build an `updateMask` from a table of API field names and whether each one is set.
+pub(crate) fn update_mask(fields: &[(&str, bool)]) -> String {
+    fields
+        .iter()
+        .filter(|(_, set)| *set)
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// An empty message, returned by methods such as deletes.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Empty {}
+
+/// A single page of results from a list method.
+///
+/// Every list method returns its items alongside a `nextPageToken`, which is absent or empty on the last page.
+pub trait Page {
+    /// The type of resource being listed.
+    type Item;
+
+    /// Token identifying the next page of results, if there is one.
+    fn next_page_token(&self) -> Option<&str>;
+
+    /// Take the items on this page.
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
 /// Possible modes of assigning coursework/announcements.
 #[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq, PartialOrd)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -14,7 +42,10 @@ pub enum AssigneeMode {
     /// All students can see the item. This is the default state.
     AllStudents,
     /// A subset of the students can see the item.
+    #[serde(rename = "INDIVIDUAL_STUDENTS")]
     IndividiualStudents,
+    /// Members of a set of student groups can see the item.
+    StudentGroups,
 }
 
 /// Assignee details about a coursework/announcement. This field is set if and only if [``AssigneeMode``] is [``AssigneeMode::IndividiualStudents``].
@@ -24,6 +55,14 @@ pub struct IndividualStudentsOptions {
     pub student_ids: Vec<String>,
 }
 
+/// Student groups assigned a coursework/announcement. This field is set if and only if [``AssigneeMode``] is [``AssigneeMode::StudentGroups``].
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq, PartialOrd)]
+#[serde(rename_all = "camelCase")]
+pub struct StudentGroupsOptions {
+    /// Identifiers of the student groups.
+    pub student_group_ids: Vec<String>,
+}
+
 /// Possible types of work
 #[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq, PartialOrd)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -39,6 +78,34 @@ pub enum CourseWorkType {
     MultipleChoiceQuestion,
 }
 
+/// A whole calendar date, such as a due date.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    /// Year of the date. Must be from 1 to 9999.
+    pub year: i32,
+    /// Month of a year. Must be from 1 to 12.
+    pub month: u32,
+    /// Day of a month. Must be from 1 to 31 and valid for the year and month.
+    pub day: u32,
+}
+
+/// A time of day, such as a due time. Times are in UTC.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TimeOfDay {
+    /// Hours of a day in 24 hour format. Must be from 0 to 23.
+    #[serde(default)]
+    pub hours: u32,
+    /// Minutes of an hour. Must be from 0 to 59.
+    #[serde(default)]
+    pub minutes: u32,
+    /// Seconds of a minute. Must be from 0 to 59.
+    #[serde(default)]
+    pub seconds: u32,
+    /// Fractions of seconds, in nanoseconds. Must be from 0 to 999,999,999.
+    #[serde(default)]
+    pub nanos: u32,
+}
+
 /// Representation of a Google Drive file.
 #[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -46,11 +113,14 @@ pub struct DriveFile {
     /// Drive API resource ID.
     pub id: String,
     /// Title of the Drive item.
-    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     /// URL that can be used to access the Drive item.
-    pub alternate_link: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alternate_link: Option<String>,
     /// URL of a thumbnail image of the Drive item.
-    pub thumbnail_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
 }
 
 /// Representation of a Google Drive folder.
@@ -72,11 +142,14 @@ pub struct Form {
     /// URL of the form.
     pub form_url: String,
     /// URL of the form responses document. Only set if respsonses have been recorded and only when the requesting user is an editor of the form.
-    pub response_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_url: Option<String>,
     /// Title of the Form.
-    pub thumbnail_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
     /// URL of a thumbnail image of the Form.
-    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
 }
 
 /// Details for a grade category in a course.
@@ -89,10 +162,12 @@ pub struct GradeCategory {
     pub id: String,
     /// Name of the grade category.
     pub name: String,
-    /// The weight of the category average as part of overall average. A weight of 12.34% is represented as 123400 (100% is 1,000,000). The last two digits should always be zero since we use two decimal precision. Only applicable when grade calculation type is WEIGHTED_CATEGORIES.
-    pub weight: String,
-    /// Default value of denominator. Only applicable when grade calculation type is TOTAL_POINTS.
-    pub default_grade_denominator: String,
+    /// The weight of the category average as part of overall average. A weight of 12.34% is represented as 123400 (100% is 1,000,000). The last two digits should always be zero since we use two decimal precision. Only applicable when grade calculation type is ``WEIGHTED_CATEGORIES``.
+    #[serde(default)]
+    pub weight: Option<u32>,
+    /// Default value of denominator. Only applicable when grade calculation type is ``TOTAL_POINTS``.
+    #[serde(default)]
+    pub default_grade_denominator: Option<u32>,
 }
 
 /// URL item.
@@ -102,9 +177,11 @@ pub struct Link {
     /// URL to link to. This must be a valid UTF-8 string containing between 1 and 2024 characters.
     pub url: String,
     /// Title of the target of the URL.
-    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     /// URL of a thumbnail image of the target URL.
-    pub thumbnail_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
 }
 
 /// Contains fields to add or remove students from a course work or announcement where the [``AssigneeMode``] is set to [``AssigneeMode::IndividiualStudents``]
@@ -117,18 +194,61 @@ pub struct ModifyIndividualStudentsOptions {
     pub remove_student_ids: Vec<String>,
 }
 
+/// Request body for the `modifyAssignees` methods of course work and announcements.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifyAssignees {
+    /// Mode of the item, which determines which students can see it.
+    pub assignee_mode: AssigneeMode,
+    /// Students to add or remove. Only used when the mode is [``AssigneeMode::IndividiualStudents``].
+    pub modify_individual_students_options: Option<ModifyIndividualStudentsOptions>,
+    /// Student groups to add or remove. Only used when the mode is [``AssigneeMode::StudentGroups``].
+    pub modify_student_groups_options: Option<ModifyStudentGroupsOptions>,
+}
+
+impl ModifyAssignees {
+    /// Give the student groups `student_group_ids` access to an item, in addition to the groups that already have it.
+    ///
+    /// This sets the item's mode to [``AssigneeMode::StudentGroups``], so access follows later changes to the groups'
+    /// membership.
+    #[must_use]
+    pub const fn add_student_groups(student_group_ids: Vec<String>) -> Self {
+        Self {
+            assignee_mode: AssigneeMode::StudentGroups,
+            modify_individual_students_options: None,
+            modify_student_groups_options: Some(ModifyStudentGroupsOptions {
+                add_student_group_ids: student_group_ids,
+                remove_student_group_ids: Vec::new(),
+            }),
+        }
+    }
+}
+
+/// Contains fields to add or remove student groups from a course work or announcement where the [``AssigneeMode``] is set to [``AssigneeMode::StudentGroups``]
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifyStudentGroupsOptions {
+    /// IDs of student groups to be added as having access to this coursework/announcement.
+    pub add_student_group_ids: Vec<String>,
+    /// IDs of student groups to be removed from having access to this coursework/announcement.
+    pub remove_student_group_ids: Vec<String>,
+}
+
 /// ``YouTube`` video item.
 #[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct YouTubeVideo {
-    /// YouTube API resource ID.
+    /// ``YouTube`` API resource ID.
     pub id: String,
-    /// Title of the YouTube video.
-    pub title: String,
-    /// URL that can be used to view the YouTube video.
-    pub alternate_link: String,
-    /// URL of a thumbnail image of the YouTube video.
-    pub thumbnail_url: String,
+    /// Title of the ``YouTube`` video.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// URL that can be used to view the ``YouTube`` video.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alternate_link: Option<String>,
+    /// URL of a thumbnail image of the ``YouTube`` video.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail_url: Option<String>,
 }
 
 /// Material attached to course work.
@@ -137,7 +257,7 @@ pub struct YouTubeVideo {
 #[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub enum Material {
-    DriveFile(DriveFile),
+    DriveFile(SharedDriveFile),
     YoutubeVideo(YouTubeVideo),
     Link(Link),
     Form(Form),
@@ -149,8 +269,9 @@ pub enum Material {
 pub struct SharedDriveFile {
     /// Inner drive file
     pub drive_file: DriveFile,
-    /// Mechanism by which students access the Drive item.
-    pub share_mode: DriveFileShareMode,
+    /// Mechanism by which students access the Drive item. Defaults to [`DriveFileShareMode::View`] when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub share_mode: Option<DriveFileShareMode>,
 }
 
 /// Possible sharing options. Defaults to VIEW if left unspecified, and other values may only be specified within a course work object of type ASSIGNMENT.
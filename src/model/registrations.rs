@@ -0,0 +1,150 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// An instruction to Classroom to send notifications from the feed to the provided destination.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Registration {
+    /// A server-generated unique identifier for this registration.
+    pub registration_id: String,
+    /// Specification for the class of notifications that Classroom should deliver to the destination.
+    pub feed: Feed,
+    /// The Cloud Pub/Sub topic that notifications are to be sent to.
+    pub cloud_pubsub_topic: CloudPubsubTopic,
+    /// The time until which the registration is effective. Registrations must be renewed before this time.
+    #[cfg(feature = "chrono")]
+    pub expiry_time: chrono::DateTime<chrono::Utc>,
+}
+
+/// Create a registration.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct RegistrationCreate {
+    /// Specification for the class of notifications that Classroom should deliver to the destination.
+    pub feed: Feed,
+    /// The Cloud Pub/Sub topic that notifications are to be sent to. Classroom must be allowed to publish to it.
+    pub cloud_pubsub_topic: CloudPubsubTopic,
+}
+
+/// A class of notifications that an application can register to receive.
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+#[serde(try_from = "RawFeed", into = "RawFeed")]
+pub enum Feed {
+    /// All roster changes for a particular domain. Requires the requesting user to be an administrator of the domain.
+    DomainRosterChanges,
+    /// All roster changes for a particular course. Requires the requesting user to be a member of the course.
+    CourseRosterChanges {
+        /// The course to be notified of roster changes for.
+        course_id: String,
+    },
+    /// All course work activity for a particular course. Requires the requesting user to be a teacher of the course.
+    CourseWorkChanges {
+        /// The course to be notified of course work changes for.
+        course_id: String,
+    },
+}
+
+impl Feed {
+    /// The `feedType` of this feed.
+    #[must_use]
+    pub const fn feed_type(&self) -> FeedType {
+        match self {
+            Self::DomainRosterChanges => FeedType::DomainRosterChanges,
+            Self::CourseRosterChanges { .. } => FeedType::CourseRosterChanges,
+            Self::CourseWorkChanges { .. } => FeedType::CourseWorkChanges,
+        }
+    }
+}
+
+/// Possible kinds of [`Feed`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(clippy::enum_variant_names)]
+pub enum FeedType {
+    /// Should never be returned or provided.
+    FeedTypeUnspecified,
+    /// All roster changes for a particular domain.
+    DomainRosterChanges,
+    /// All roster changes for a particular course.
+    CourseRosterChanges,
+    /// All course work activity for a particular course.
+    CourseWorkChanges,
+}
+
+/// The wire form of a [`Feed`].
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawFeed {
+    feed_type: FeedType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    course_roster_changes_info: Option<CourseInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    course_work_changes_info: Option<CourseInfo>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CourseInfo {
+    course_id: String,
+}
+
+impl TryFrom<RawFeed> for Feed {
+    type Error = InvalidFeed;
+
+    fn try_from(raw: RawFeed) -> Result<Self, Self::Error> {
+        match raw.feed_type {
+            FeedType::DomainRosterChanges => Ok(Self::DomainRosterChanges),
+            FeedType::CourseRosterChanges => raw
+                .course_roster_changes_info
+                .map(|info| Self::CourseRosterChanges {
+                    course_id: info.course_id,
+                })
+                .ok_or(InvalidFeed(raw.feed_type)),
+            FeedType::CourseWorkChanges => raw
+                .course_work_changes_info
+                .map(|info| Self::CourseWorkChanges {
+                    course_id: info.course_id,
+                })
+                .ok_or(InvalidFeed(raw.feed_type)),
+            FeedType::FeedTypeUnspecified => Err(InvalidFeed(raw.feed_type)),
+        }
+    }
+}
+
+impl From<Feed> for RawFeed {
+    fn from(feed: Feed) -> Self {
+        let feed_type = feed.feed_type();
+        let (course_roster_changes_info, course_work_changes_info) = match feed {
+            Feed::DomainRosterChanges => (None, None),
+            Feed::CourseRosterChanges { course_id } => (Some(CourseInfo { course_id }), None),
+            Feed::CourseWorkChanges { course_id } => (None, Some(CourseInfo { course_id })),
+        };
+        Self {
+            feed_type,
+            course_roster_changes_info,
+            course_work_changes_info,
+        }
+    }
+}
+
+/// A feed was missing the information its `feedType` requires.
+#[derive(Debug)]
+struct InvalidFeed(FeedType);
+
+impl fmt::Display for InvalidFeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid feed of type {:?}", self.0)
+    }
+}
+
+/// A reference to a Cloud Pub/Sub topic.
+///
+/// To register for notifications, the owner of the topic must grant `classroom-notifications@system.gserviceaccount.com` the `projects.topics.publish` permission.
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudPubsubTopic {
+    /// The name of the topic, in the form `projects/{project}/topics/{topic}`.
+    pub topic_name: String,
+}
@@ -1 +1,83 @@
+use serde::{Deserialize, Serialize};
 
+use crate::model::Page;
+
+/// An invitation to become the guardian of a specified user, sent to a specified email address.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GuardianInvitation {
+    /// ID of the student (in standard format)
+    pub student_id: String,
+    /// Unique identifier for this invitation.
+    pub invitation_id: String,
+    /// Email address that the invitation was sent to.
+    pub invited_email_address: String,
+    /// The state that this invitation is in.
+    pub state: GuardianInvitationState,
+    /// The time that this invitation was created.
+    #[cfg(feature = "chrono")]
+    pub creation_time: chrono::DateTime<chrono::Utc>,
+}
+
+/// Create a guardian invitation.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct GuardianInvitationCreate {
+    /// Email address that the invitation should be sent to.
+    pub invited_email_address: String,
+}
+
+/// Modify a guardian invitation.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct GuardianInvitationModify {
+    /// The state of the invitation. The only valid modification is from [`GuardianInvitationState::Pending`] to [`GuardianInvitationState::Complete`], which cancels the invitation.
+    pub state: Option<GuardianInvitationState>,
+}
+
+impl GuardianInvitationModify {
+    /// The `updateMask` for this modification: the API names of every field that is [`Some`], comma-separated.
+    #[must_use]
+    pub fn update_mask(&self) -> String {
+        crate::model::update_mask(&[("state", self.state.is_some())])
+    }
+}
+
+/// The state that a guardian invitation may be in.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[allow(clippy::module_name_repetitions)]
+pub enum GuardianInvitationState {
+    /// Should never be returned.
+    GuardianInvitationStateUnspecified,
+    /// The invitation is active and awaiting a response.
+    Pending,
+    /// The invitation is no longer active. It may have been accepted, declined, withdrawn or it may have expired.
+    Complete,
+}
+
+/// One page of guardian invitations, as returned by `userProfiles.guardianInvitations.list`.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct ListGuardianInvitationsResponse {
+    /// Guardian invitations that matched the list request.
+    #[serde(default)]
+    pub guardian_invitations: Vec<GuardianInvitation>,
+    /// Token identifying the next page of results to return. If empty, no further results are available.
+    pub next_page_token: Option<String>,
+}
+
+impl Page for ListGuardianInvitationsResponse {
+    type Item = GuardianInvitation;
+
+    fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.guardian_invitations
+    }
+}
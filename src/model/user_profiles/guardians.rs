@@ -1 +1,42 @@
+use serde::{Deserialize, Serialize};
 
+use super::UserProfile;
+use crate::model::Page;
+
+/// Association between a student and a guardian of that student. The guardian may receive information about the student's course work.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Guardian {
+    /// Identifier for the student to whom the guardian relationship applies.
+    pub student_id: String,
+    /// Identifier for the guardian.
+    pub guardian_id: String,
+    /// User profile for the guardian.
+    pub guardian_profile: UserProfile,
+    /// The email address to which the initial guardian invitation was sent. This field is only visible to domain administrators.
+    pub invited_email_address: Option<String>,
+}
+
+/// One page of guardians, as returned by `userProfiles.guardians.list`.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct ListGuardiansResponse {
+    /// Guardians on this page of results that met the criteria specified in the request.
+    #[serde(default)]
+    pub guardians: Vec<Guardian>,
+    /// Token identifying the next page of results to return. If empty, no further results are available.
+    pub next_page_token: Option<String>,
+}
+
+impl Page for ListGuardiansResponse {
+    type Item = Guardian;
+
+    fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.guardians
+    }
+}
@@ -1,2 +1,213 @@
+use std::{fmt, str::FromStr};
+
+use serde::{de::Visitor, Deserialize, Serialize};
+
 pub mod guardian_invitations;
 pub mod guardians;
+
+/// Global information for a user.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::module_name_repetitions)]
+pub struct UserProfile {
+    /// Identifier of the user.
+    pub id: String,
+    /// Name of the user.
+    pub name: Name,
+    /// Email address of the user. Must request `https://www.googleapis.com/auth/classroom.profile.emails` scope for this field to be populated in a response body.
+    pub email_address: Option<String>,
+    /// URL of user's profile photo. Must request `https://www.googleapis.com/auth/classroom.profile.photos` scope for this field to be populated in a response body.
+    pub photo_url: Option<String>,
+    /// Global permissions of the user.
+    #[serde(default)]
+    pub permissions: Vec<GlobalPermission>,
+    /// Represents whether a Google Workspace for Education user's domain administrator has explicitly verified them as being a teacher. This field is always false if the user is not a member of a Google Workspace for Education domain.
+    pub verified_teacher: Option<bool>,
+}
+
+/// Details of the user's name.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Name {
+    /// The user's first name.
+    pub given_name: Option<String>,
+    /// The user's last name.
+    pub family_name: Option<String>,
+    /// The user's full name formed by concatenating the first and last name values.
+    pub full_name: Option<String>,
+}
+
+/// Global user permission description.
+#[derive(Serialize, Deserialize, Debug, Hash, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GlobalPermission {
+    /// Permission value.
+    pub permission: Permission,
+}
+
+/// Possible global permissions.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Permission {
+    /// No permission is specified. This is not returned and is not a valid value.
+    PermissionUnspecified,
+    /// User is permitted to create a course.
+    CreateCourse,
+}
+
+/// Identifies a user, either by email, by their unique numeric id, or as the requesting user.
+///
+/// This is a simple wrapper type, no guarentees are made.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum UserId {
+    /// Email (unchecked)
+    Email(String),
+    /// Unique numeric ID (numeric only checked in deserialization)
+    Id(String),
+    /// Specifies the current user
+    Me,
+}
+
+impl UserId {
+    /// Classify an identifier as the literal `me`, an all-digit numeric id, or otherwise an email.
+    ///
+    /// # Errors
+    /// Errors if `value` is empty.
+    pub fn parse(value: &str) -> Result<Self, ParseUserIdError> {
+        if value.is_empty() {
+            return Err(ParseUserIdError);
+        }
+        if value == "me" {
+            return Ok(Self::Me);
+        }
+        if value.chars().all(|c| c.is_ascii_digit()) {
+            return Ok(Self::Id(value.to_string()));
+        }
+        Ok(Self::Email(value.to_string()))
+    }
+
+    /// Parse `value` for use as a user identifier in a request, leaving an empty string as an [`UserId::Id`] for the
+    /// API to report.
+    fn lenient(value: &str) -> Self {
+        Self::parse(value).unwrap_or_else(|_| Self::Id(value.to_string()))
+    }
+
+    /// The identifier as sent to the API.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Email(email) => email,
+            Self::Id(id) => id,
+            Self::Me => "me",
+        }
+    }
+}
+
+impl std::fmt::Display for UserId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for UserId {
+    type Err = ParseUserIdError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+impl From<&str> for UserId {
+    fn from(value: &str) -> Self {
+        Self::lenient(value)
+    }
+}
+
+impl From<&String> for UserId {
+    fn from(value: &String) -> Self {
+        Self::lenient(value)
+    }
+}
+
+impl From<String> for UserId {
+    fn from(value: String) -> Self {
+        Self::lenient(&value)
+    }
+}
+
+impl From<&Self> for UserId {
+    fn from(value: &Self) -> Self {
+        value.clone()
+    }
+}
+
+/// A user identifier was empty, from [`UserId::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseUserIdError;
+
+impl fmt::Display for ParseUserIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("user identifier is empty")
+    }
+}
+
+impl std::error::Error for ParseUserIdError {}
+
+impl Serialize for UserId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+struct UserIdVisitor;
+
+impl Visitor<'_> for UserIdVisitor {
+    type Value = UserId;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("Expected `str`")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        UserId::parse(v).map_err(E::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for UserId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_string(UserIdVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_user_ids() {
+        assert_eq!(UserId::parse("me"), Ok(UserId::Me));
+        assert_eq!(
+            UserId::parse("123456"),
+            Ok(UserId::Id("123456".to_string()))
+        );
+        assert_eq!(
+            UserId::parse("student@example.edu"),
+            Ok(UserId::Email("student@example.edu".to_string()))
+        );
+    }
+
+    #[test]
+    fn rejects_empty_user_id() {
+        assert_eq!(UserId::parse(""), Err(ParseUserIdError));
+        assert!(serde_json::from_str::<UserId>(r#""""#).is_err());
+    }
+}
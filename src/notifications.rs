@@ -0,0 +1,424 @@
+//! Decoding the Classroom change notifications delivered through Cloud Pub/Sub.
+//!
+//! Notifications are published for each [`Registration`](crate::model::registrations::Registration). A push
+//! subscription delivers a [`PushRequest`] to your endpoint, while a pull subscription returns
+//! [`ReceivedMessage`]s. Either way, the [`PubsubMessage`] inside is decoded with [`Notification::decode`], which
+//! also reads the registration it was published for from the message attributes.
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+
+use crate::model::{
+    courses::aliases::AliasScope, invitations::CourseRole, registrations::FeedType,
+    user_profiles::UserId,
+};
+
+/// The body of a request made by a Pub/Sub push subscription.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PushRequest {
+    /// The delivered message.
+    pub message: PubsubMessage,
+    /// The subscription the message was delivered for, in the form `projects/{project}/subscriptions/{subscription}`.
+    pub subscription: String,
+}
+
+/// A message returned by a Pub/Sub pull.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceivedMessage {
+    /// Identifier used to acknowledge the message.
+    pub ack_id: String,
+    /// The received message.
+    pub message: PubsubMessage,
+}
+
+/// A Pub/Sub message.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PubsubMessage {
+    /// The base64-encoded message payload.
+    #[serde(default)]
+    pub data: String,
+    /// Attributes attached to the message.
+    #[serde(default)]
+    pub attributes: HashMap<String, String>,
+    /// Identifier assigned by Pub/Sub.
+    pub message_id: Option<String>,
+    /// When Pub/Sub received the message.
+    #[cfg(feature = "chrono")]
+    pub publish_time: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl PubsubMessage {
+    /// The decoded message payload.
+    ///
+    /// # Errors
+    /// Errors if `data` is not valid base64.
+    pub fn payload(&self) -> Result<Vec<u8>, DecodeError> {
+        STANDARD.decode(&self.data).map_err(DecodeError::Base64)
+    }
+}
+
+/// A change notification from Classroom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Notification {
+    /// A student or teacher joined or left a course, from a [`FeedType::CourseRosterChanges`] registration.
+    CourseRoster(RosterChange),
+    /// A student or teacher joined or left a course, from a [`FeedType::DomainRosterChanges`] registration.
+    DomainRoster(RosterChange),
+    /// Course work or a student submission changed, from a [`FeedType::CourseWorkChanges`] registration.
+    CourseWork(CourseWorkChange),
+}
+
+impl Notification {
+    /// Decode a Pub/Sub message published for a registration of type `feed_type`.
+    ///
+    /// Roster notifications have the same payload for domain and course registrations, so `feed_type` is needed to
+    /// tell them apart. It is usually known from the subscription the message arrived on.
+    ///
+    /// # Errors
+    /// Errors if the payload is not valid base64 or JSON, or does not describe a change in `feed_type`.
+    pub fn decode(
+        message: &PubsubMessage,
+        feed_type: FeedType,
+    ) -> Result<NotificationMessage, DecodeError> {
+        Ok(NotificationMessage {
+            registration_id: message.attributes.get("registrationId").cloned(),
+            notification: Self::from_json(&message.payload()?, feed_type)?,
+        })
+    }
+
+    /// Decode the JSON payload of a notification published for a registration of type `feed_type`.
+    ///
+    /// # Errors
+    /// Errors if the payload is not valid JSON, or does not describe a change in `feed_type`.
+    pub fn from_json(payload: &[u8], feed_type: FeedType) -> Result<Self, DecodeError> {
+        let raw: RawNotification = serde_json::from_slice(payload).map_err(DecodeError::Json)?;
+        match (feed_type, raw.collection.as_str()) {
+            (FeedType::CourseRosterChanges, "courses.students" | "courses.teachers") => {
+                Ok(Self::CourseRoster(RosterChange::new(raw)?))
+            }
+            (FeedType::DomainRosterChanges, "courses.students" | "courses.teachers") => {
+                Ok(Self::DomainRoster(RosterChange::new(raw)?))
+            }
+            (FeedType::CourseWorkChanges, "courses.courseWork") => {
+                Ok(Self::CourseWork(CourseWorkChange::CourseWork {
+                    event_type: raw.event_type,
+                    course_id: AliasScope::Id(raw.resource_id.course_id),
+                    course_work_id: required(raw.resource_id.id, "id")?,
+                }))
+            }
+            (FeedType::CourseWorkChanges, "courses.courseWork.studentSubmissions") => {
+                Ok(Self::CourseWork(CourseWorkChange::StudentSubmission {
+                    event_type: raw.event_type,
+                    course_id: AliasScope::Id(raw.resource_id.course_id),
+                    course_work_id: required(raw.resource_id.course_work_id, "courseWorkId")?,
+                    id: required(raw.resource_id.id, "id")?,
+                }))
+            }
+            _ => Err(DecodeError::UnexpectedCollection {
+                feed_type,
+                collection: raw.collection,
+            }),
+        }
+    }
+}
+
+/// A notification decoded from a Pub/Sub message, by [`Notification::decode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationMessage {
+    /// The [`Registration`](crate::model::registrations::Registration) the notification was published for, from the
+    /// message's `registrationId` attribute. Useful for telling registrations that share a topic apart.
+    pub registration_id: Option<String>,
+    /// The change described by the message.
+    pub notification: Notification,
+}
+
+/// A member was added to or removed from a course.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RosterChange {
+    /// Whether the change was to a student or teacher.
+    pub role: CourseRole,
+    /// What happened to the member.
+    pub event_type: EventType,
+    /// The course whose roster changed.
+    pub course_id: AliasScope,
+    /// The user who joined or left the course.
+    pub user_id: UserId,
+}
+
+impl RosterChange {
+    fn new(raw: RawNotification) -> Result<Self, DecodeError> {
+        let role = if raw.collection == "courses.students" {
+            CourseRole::Student
+        } else {
+            CourseRole::Teacher
+        };
+        Ok(Self {
+            role,
+            event_type: raw.event_type,
+            course_id: AliasScope::Id(raw.resource_id.course_id),
+            user_id: UserId::Id(required(raw.resource_id.user_id, "userId")?),
+        })
+    }
+}
+
+/// Course work, or a submission for it, changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CourseWorkChange {
+    /// Course work was created, modified or deleted.
+    CourseWork {
+        /// What happened to the course work.
+        event_type: EventType,
+        /// The course the course work belongs to.
+        course_id: AliasScope,
+        /// The course work that changed.
+        course_work_id: String,
+    },
+    /// A student submission was created or modified.
+    StudentSubmission {
+        /// What happened to the submission.
+        event_type: EventType,
+        /// The course the submission belongs to.
+        course_id: AliasScope,
+        /// The course work the submission is for.
+        course_work_id: String,
+        /// The submission that changed.
+        id: String,
+    },
+}
+
+/// Possible kinds of change to a resource.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EventType {
+    /// The resource was created.
+    Created,
+    /// The resource was modified.
+    Modified,
+    /// The resource was deleted.
+    Deleted,
+}
+
+/// The JSON payload of a notification.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawNotification {
+    collection: String,
+    event_type: EventType,
+    resource_id: ResourceId,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResourceId {
+    course_id: String,
+    user_id: Option<String>,
+    course_work_id: Option<String>,
+    id: Option<String>,
+}
+
+fn required(value: Option<String>, field: &'static str) -> Result<String, DecodeError> {
+    value.ok_or(DecodeError::MissingField(field))
+}
+
+/// A Pub/Sub message could not be decoded as a Classroom notification.
+#[derive(Debug)]
+pub enum DecodeError {
+    /// The message data is not valid base64.
+    Base64(base64::DecodeError),
+    /// The payload is not a valid notification.
+    Json(serde_json::Error),
+    /// The notification is missing a resource identifier its collection requires.
+    MissingField(&'static str),
+    /// The notification is for a collection that the registration's feed does not publish.
+    UnexpectedCollection {
+        /// The feed the notification was decoded for.
+        feed_type: FeedType,
+        /// The collection named in the notification.
+        collection: String,
+    },
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Base64(source) => write!(f, "invalid message data: {source}"),
+            Self::Json(source) => write!(f, "invalid notification: {source}"),
+            Self::MissingField(field) => write!(f, "notification is missing resourceId.{field}"),
+            Self::UnexpectedCollection {
+                feed_type,
+                collection,
+            } => write!(
+                f,
+                "unexpected collection {collection} for a {feed_type:?} feed"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Base64(source) => Some(source),
+            Self::Json(source) => Some(source),
+            Self::MissingField(_) | Self::UnexpectedCollection { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(feed_type: FeedType, payload: &str) -> Result<Notification, DecodeError> {
+        Notification::from_json(payload.as_bytes(), feed_type)
+    }
+
+    fn roster(collection: &str) -> String {
+        format!(
+            r#"{{"collection":"{collection}","eventType":"CREATED","resourceId":{{"courseId":"1","userId":"2"}}}}"#
+        )
+    }
+
+    const COURSE_WORK: &str = r#"{"collection":"courses.courseWork","eventType":"MODIFIED","resourceId":{"courseId":"1","id":"3"}}"#;
+    const SUBMISSION: &str = r#"{"collection":"courses.courseWork.studentSubmissions","eventType":"MODIFIED","resourceId":{"courseId":"1","courseWorkId":"3","id":"4"}}"#;
+
+    fn roster_change(role: CourseRole) -> RosterChange {
+        RosterChange {
+            role,
+            event_type: EventType::Created,
+            course_id: AliasScope::Id("1".to_string()),
+            user_id: UserId::Id("2".to_string()),
+        }
+    }
+
+    #[test]
+    fn decodes_course_roster_changes() {
+        assert_eq!(
+            decode(FeedType::CourseRosterChanges, &roster("courses.students")).unwrap(),
+            Notification::CourseRoster(roster_change(CourseRole::Student))
+        );
+        assert_eq!(
+            decode(FeedType::CourseRosterChanges, &roster("courses.teachers")).unwrap(),
+            Notification::CourseRoster(roster_change(CourseRole::Teacher))
+        );
+    }
+
+    #[test]
+    fn decodes_domain_roster_changes() {
+        assert_eq!(
+            decode(FeedType::DomainRosterChanges, &roster("courses.students")).unwrap(),
+            Notification::DomainRoster(roster_change(CourseRole::Student))
+        );
+        assert_eq!(
+            decode(FeedType::DomainRosterChanges, &roster("courses.teachers")).unwrap(),
+            Notification::DomainRoster(roster_change(CourseRole::Teacher))
+        );
+    }
+
+    #[test]
+    fn decodes_course_work_changes() {
+        assert_eq!(
+            decode(FeedType::CourseWorkChanges, COURSE_WORK).unwrap(),
+            Notification::CourseWork(CourseWorkChange::CourseWork {
+                event_type: EventType::Modified,
+                course_id: AliasScope::Id("1".to_string()),
+                course_work_id: "3".to_string(),
+            })
+        );
+        assert_eq!(
+            decode(FeedType::CourseWorkChanges, SUBMISSION).unwrap(),
+            Notification::CourseWork(CourseWorkChange::StudentSubmission {
+                event_type: EventType::Modified,
+                course_id: AliasScope::Id("1".to_string()),
+                course_work_id: "3".to_string(),
+                id: "4".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_collections_outside_the_feed() {
+        let cases = [
+            (FeedType::CourseRosterChanges, COURSE_WORK.to_string()),
+            (FeedType::DomainRosterChanges, SUBMISSION.to_string()),
+            (FeedType::CourseWorkChanges, roster("courses.students")),
+            (FeedType::CourseRosterChanges, roster("courses.guardians")),
+        ];
+        for (feed_type, payload) in cases {
+            assert!(
+                matches!(
+                    decode(feed_type, &payload),
+                    Err(DecodeError::UnexpectedCollection { .. })
+                ),
+                "{payload} decoded for {feed_type:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn reports_missing_resource_ids() {
+        let cases = [
+            (
+                FeedType::CourseRosterChanges,
+                r#"{"collection":"courses.students","eventType":"DELETED","resourceId":{"courseId":"1"}}"#,
+                "userId",
+            ),
+            (
+                FeedType::CourseWorkChanges,
+                r#"{"collection":"courses.courseWork","eventType":"DELETED","resourceId":{"courseId":"1"}}"#,
+                "id",
+            ),
+            (
+                FeedType::CourseWorkChanges,
+                r#"{"collection":"courses.courseWork.studentSubmissions","eventType":"CREATED","resourceId":{"courseId":"1","id":"4"}}"#,
+                "courseWorkId",
+            ),
+        ];
+        for (feed_type, payload, field) in cases {
+            assert!(
+                matches!(decode(feed_type, payload), Err(DecodeError::MissingField(missing)) if missing == field),
+                "{payload} decoded without {field}"
+            );
+        }
+        assert!(matches!(
+            decode(
+                FeedType::CourseWorkChanges,
+                r#"{"collection":"courses.courseWork","eventType":"CREATED","resourceId":{"id":"3"}}"#
+            ),
+            Err(DecodeError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn decodes_message_with_registration_id() {
+        let message = PubsubMessage {
+            data: STANDARD.encode(COURSE_WORK),
+            attributes: HashMap::from([("registrationId".to_string(), "r1".to_string())]),
+            message_id: Some("m1".to_string()),
+            #[cfg(feature = "chrono")]
+            publish_time: None,
+        };
+        let decoded = Notification::decode(&message, FeedType::CourseWorkChanges).unwrap();
+        assert_eq!(decoded.registration_id.as_deref(), Some("r1"));
+        assert!(matches!(
+            decoded.notification,
+            Notification::CourseWork(CourseWorkChange::CourseWork { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_base64() {
+        let message: PubsubMessage = serde_json::from_str(r#"{"data":"not base64!"}"#).unwrap();
+        assert!(matches!(
+            Notification::decode(&message, FeedType::CourseWorkChanges),
+            Err(DecodeError::Base64(_))
+        ));
+    }
+}